@@ -0,0 +1,267 @@
+use crate::vocabulary::Vocabulary;
+use std::collections::{HashMap, VecDeque};
+
+const ROOT: usize = 0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScanMode {
+    /// Report every word, even when it shares letters with another, so "oneight" is 1 and 8.
+    Overlapping,
+    /// Read left to right, taking the longest word at each position and skipping its letters.
+    NonOverlapping,
+}
+
+impl ScanMode {
+    pub fn from_name(name: &str) -> Result<ScanMode, String> {
+        match name {
+            "overlapping" => Ok(ScanMode::Overlapping),
+            "non-overlapping" => Ok(ScanMode::NonOverlapping),
+            _ => Err(format!("Unknown scan mode: {}", name)),
+        }
+    }
+}
+
+/// A word found in a line, with `start..end` counted in characters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Match {
+    pub start: usize,
+    pub end: usize,
+    pub value: u32,
+}
+
+#[derive(Debug, Clone, Default)]
+struct Node {
+    next: HashMap<u8, usize>,
+    /// Longest proper suffix of this node's prefix that is also a prefix in the trie.
+    fail: usize,
+    /// The word ending here, as `(length in characters, value)`.
+    word: Option<(usize, u32)>,
+    /// Nearest node along the fail links that ends a word.
+    dictionary: Option<usize>,
+}
+
+/// Aho–Corasick automaton over the UTF-8 bytes of a vocabulary's words, finding all of them
+/// in one pass.
+#[derive(Debug, Clone)]
+pub struct Automaton {
+    nodes: Vec<Node>,
+    /// Full transition table, `delta[state * 256 + byte]`, with the fail links folded in.
+    delta: Vec<usize>,
+    /// Length in characters of the longest word.
+    longest: usize,
+}
+
+impl Automaton {
+    pub fn new(vocabulary: &Vocabulary) -> Automaton {
+        let mut nodes = vec![Node::default()];
+
+        for (word, value) in vocabulary.words() {
+            let mut state = ROOT;
+
+            for b in word.bytes() {
+                state = match nodes[state].next.get(&b) {
+                    Some(next) => *next,
+                    None => {
+                        nodes.push(Node::default());
+                        let next = nodes.len() - 1;
+                        nodes[state].next.insert(b, next);
+                        next
+                    }
+                };
+            }
+
+            nodes[state].word = Some((word.chars().count(), value));
+        }
+
+        let mut delta = vec![ROOT; nodes.len() * 256];
+
+        for (b, next) in &nodes[ROOT].next {
+            delta[*b as usize] = *next;
+        }
+
+        // Breadth first, so every fail target is finished before it is used
+        let mut queue = nodes[ROOT].next.values().copied().collect::<VecDeque<_>>();
+
+        while let Some(state) = queue.pop_front() {
+            let fail = nodes[state].fail;
+
+            for b in 0..256 {
+                delta[state * 256 + b] = match nodes[state].next.get(&(b as u8)) {
+                    Some(next) => *next,
+                    None => delta[fail * 256 + b],
+                };
+            }
+
+            let edges = nodes[state]
+                .next
+                .iter()
+                .map(|(b, next)| (*b, *next))
+                .collect::<Vec<_>>();
+
+            for (b, next) in edges {
+                let fail = delta[fail * 256 + b as usize];
+
+                nodes[next].fail = fail;
+                nodes[next].dictionary = match nodes[fail].word {
+                    Some(_) => Some(fail),
+                    None => nodes[fail].dictionary,
+                };
+                queue.push_back(next);
+            }
+        }
+
+        let longest = vocabulary
+            .words()
+            .map(|(word, _)| word.chars().count())
+            .max()
+            .unwrap_or(0);
+
+        Automaton {
+            nodes,
+            delta,
+            longest,
+        }
+    }
+
+    pub(crate) fn start(&self) -> usize {
+        ROOT
+    }
+
+    pub(crate) fn step(&self, state: usize, b: u8) -> usize {
+        self.delta[state * 256 + b as usize]
+    }
+
+    pub(crate) fn longest(&self) -> usize {
+        self.longest
+    }
+
+    /// Calls `found` with `(length in characters, value)` for every word ending in `state`.
+    pub(crate) fn words_at(&self, state: usize, mut found: impl FnMut(usize, u32)) {
+        let mut node = Some(state);
+
+        while let Some(i) = node {
+            if let Some((len, value)) = self.nodes[i].word {
+                found(len, value);
+            }
+            node = self.nodes[i].dictionary;
+        }
+    }
+
+    /// Every occurrence of every word in `text`, ordered by start and then longest first.
+    fn find_overlapping(&self, text: &str) -> Vec<Match> {
+        let mut output = vec![];
+        let mut state = ROOT;
+        let mut chars = 0;
+
+        for b in text.bytes() {
+            state = self.step(state, b);
+
+            // Only the first byte of a character starts a new one
+            if b & 0xc0 != 0x80 {
+                chars += 1;
+            }
+
+            self.words_at(state, |len, value| {
+                output.push(Match {
+                    start: chars - len,
+                    end: chars,
+                    value,
+                })
+            });
+        }
+
+        output.sort_by_key(|m| (m.start, std::cmp::Reverse(m.end)));
+        output
+    }
+
+    pub fn find(&self, text: &str, mode: ScanMode) -> Vec<Match> {
+        let matches = self.find_overlapping(text);
+
+        match mode {
+            ScanMode::Overlapping => matches,
+            ScanMode::NonOverlapping => {
+                let mut output: Vec<Match> = vec![];
+
+                for m in matches {
+                    if output.last().is_none_or(|last| m.start >= last.end) {
+                        output.push(m);
+                    }
+                }
+
+                output
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn starts(matches: &[Match]) -> Vec<(usize, u32)> {
+        matches.iter().map(|m| (m.start, m.value)).collect()
+    }
+
+    #[test]
+    fn finds_overlapping_words() {
+        let automaton = Automaton::new(&Vocabulary::english());
+        let matches = automaton.find("xtwone3four", ScanMode::Overlapping);
+
+        assert_eq!(starts(&matches), vec![(1, 2), (3, 1), (6, 3), (7, 4)]);
+        assert_eq!(
+            matches[1],
+            Match {
+                start: 3,
+                end: 6,
+                value: 1
+            }
+        );
+    }
+
+    #[test]
+    fn skips_consumed_letters() {
+        let automaton = Automaton::new(&Vocabulary::english());
+
+        assert_eq!(
+            starts(&automaton.find("xtwone3four", ScanMode::NonOverlapping)),
+            vec![(1, 2), (6, 3), (7, 4)]
+        );
+    }
+
+    #[test]
+    fn follows_fail_links_into_suffixes() {
+        let mut vocabulary = Vocabulary::new();
+        vocabulary.add("seventeen", 7).unwrap();
+        vocabulary.add("seven", 7).unwrap();
+        vocabulary.add("teen", 1).unwrap();
+        vocabulary.add("een", 2).unwrap();
+
+        let automaton = Automaton::new(&vocabulary);
+
+        assert_eq!(
+            starts(&automaton.find("seventeen", ScanMode::Overlapping)),
+            vec![(0, 7), (0, 7), (5, 1), (6, 2)]
+        );
+        assert_eq!(
+            starts(&automaton.find("sevenseventeen", ScanMode::NonOverlapping)),
+            vec![(0, 7), (5, 7)]
+        );
+    }
+
+    #[test]
+    fn positions_count_characters() {
+        let mut vocabulary = Vocabulary::new();
+        vocabulary.add("fünf", 5).unwrap();
+
+        let automaton = Automaton::new(&vocabulary);
+
+        assert_eq!(
+            automaton.find("ääfünf", ScanMode::Overlapping),
+            vec![Match {
+                start: 2,
+                end: 6,
+                value: 5
+            }]
+        );
+    }
+}
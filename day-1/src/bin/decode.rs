@@ -0,0 +1,45 @@
+use day_1::{Decoder, ScanMode, Vocabulary};
+use std::fs;
+
+/// Decodes `input` with the vocabulary named `vocabulary`. With `report`, every line is
+/// printed along with the words found on it.
+fn process(input: &str, vocabulary: &str, mode: ScanMode, report: bool) -> Result<u64, String> {
+    let decoder = Decoder::new(&Vocabulary::load(vocabulary)?, mode);
+
+    if report {
+        let reports = decoder.report(input);
+
+        for line in &reports {
+            print!("{}", line);
+        }
+
+        let zero = reports
+            .iter()
+            .filter(|line| line.warning().is_some())
+            .count();
+        println!("{} of {} lines contribute 0", zero, reports.len());
+    }
+
+    Ok(decoder.decode(input))
+}
+
+fn main() {
+    let report = std::env::args().any(|arg| arg == "--report");
+    let args = std::env::args()
+        .filter(|arg| arg != "--report")
+        .collect::<Vec<_>>();
+    let vocabulary = args.get(1).map(String::as_str).unwrap_or("english");
+    let mode = match ScanMode::from_name(args.get(2).map(String::as_str).unwrap_or("overlapping")) {
+        Ok(mode) => mode,
+        Err(err) => return println!("Error: {}", err),
+    };
+    let input = match args.get(3) {
+        Some(path) => fs::read_to_string(path).map_err(|err| err.to_string()),
+        None => Ok(include_str!("./input-2.txt").to_owned()),
+    };
+
+    match input.and_then(|input| process(&input, vocabulary, mode, report)) {
+        Ok(result) => println!("Output: {}", result),
+        Err(err) => println!("Error: {}", err),
+    }
+}
@@ -1,20 +1,7 @@
-fn resolve(source: &str) -> u32 {
-    let mut sum = 0;
+use day_1::{Decoder, ScanMode, Vocabulary};
 
-    for line in source.lines() {
-        let nums: Vec<u32> = line.chars().filter_map(|a| a.to_digit(10)).collect();
-
-        let first = nums.first().unwrap_or(&0);
-        let last = nums.last().unwrap_or(&0);
-
-        let combined = format!("{}{}", first, last);
-
-        let result = combined.parse::<u32>().unwrap();
-
-        sum += result;
-    }
-
-    sum
+fn resolve(source: &str) -> u64 {
+    Decoder::new(&Vocabulary::digits(), ScanMode::Overlapping).decode(source)
 }
 
 fn main() {
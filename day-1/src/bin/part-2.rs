@@ -1,90 +1,8 @@
-fn resolve(input: &str) -> u32 {
-    let mut sum = 0;
+use day_1::{Decoder, ScanMode, Vocabulary};
 
-    for line in input.lines() {
-        let mut result: Vec<u32> = vec![];
-        let buffer = line.chars().collect::<Vec<char>>();
-        let mut index = 0;
-
-        while index < buffer.len() {
-            let c = buffer[index];
-
-            if c.is_digit(10) {
-                result.push(c.to_digit(10).unwrap());
-                index += 1;
-                continue;
-            }
-
-            let mut cursor = 0;
-            let mut word = String::new();
-
-            while cursor < 6 && index + cursor < buffer.len() {
-                let letter = buffer[index + cursor];
-
-                if letter.is_digit(10) {
-                    break;
-                }
-
-                word.push(letter);
-
-                match word.as_str() {
-                    "nine" => {
-                        result.push(9);
-                        break;
-                    }
-                    "eight" => {
-                        result.push(8);
-                        break;
-                    }
-                    "seven" => {
-                        result.push(7);
-                        break;
-                    }
-                    "six" => {
-                        result.push(6);
-                        break;
-                    }
-                    "five" => {
-                        result.push(5);
-                        break;
-                    }
-                    "four" => {
-                        result.push(4);
-                        break;
-                    }
-                    "three" => {
-                        result.push(3);
-                        break;
-                    }
-                    "two" => {
-                        result.push(2);
-                        break;
-                    }
-                    "one" => {
-                        result.push(1);
-                        break;
-                    }
-                    "zero" => {
-                        result.push(0);
-                        break;
-                    }
-                    _ => {
-                        cursor += 1;
-                    }
-                }
-            }
-
-            index += 1
-        }
-
-        let first = result.first().unwrap_or(&0);
-        let last = result.last().unwrap_or(&0);
-
-        let combined = format!("{}{}", first, last);
-        sum += combined.parse::<u32>().unwrap();
-    }
-
-    return sum;
+fn resolve(input: &str) -> u64 {
+    // Words may share letters, as in "eightwo"
+    Decoder::new(&Vocabulary::english(), ScanMode::Overlapping).decode(input)
 }
 
 fn main() {
@@ -0,0 +1,34 @@
+use day_1::{Decoder, ScanMode, Vocabulary};
+use std::fs::File;
+use std::io::{self, BufReader};
+
+/// Sums the calibration values of the file at `path`, or of stdin for `-`, without loading it
+/// into memory.
+fn process(path: &str, vocabulary: &str, mode: ScanMode) -> Result<u64, String> {
+    let decoder = Decoder::new(&Vocabulary::load(vocabulary)?, mode);
+
+    let result = match path {
+        "-" => decoder.decode_reader(io::stdin().lock()),
+        path => {
+            let file = File::open(path).map_err(|err| format!("{}: {}", path, err))?;
+            decoder.decode_reader(BufReader::new(file))
+        }
+    };
+
+    result.map_err(|err| err.to_string())
+}
+
+fn main() {
+    let args = std::env::args().collect::<Vec<_>>();
+    let path = args.get(1).map(String::as_str).unwrap_or("-");
+    let vocabulary = args.get(2).map(String::as_str).unwrap_or("english");
+    let mode = match ScanMode::from_name(args.get(3).map(String::as_str).unwrap_or("overlapping")) {
+        Ok(mode) => mode,
+        Err(err) => return println!("Error: {}", err),
+    };
+
+    match process(path, vocabulary, mode) {
+        Ok(result) => println!("Output: {}", result),
+        Err(err) => println!("Error: {}", err),
+    }
+}
@@ -0,0 +1,103 @@
+pub mod automaton;
+pub mod report;
+pub mod stream;
+pub mod vocabulary;
+
+pub use automaton::{Automaton, Match, ScanMode};
+pub use report::LineReport;
+pub use vocabulary::Vocabulary;
+
+/// Turns calibration lines into values using the words of a vocabulary.
+#[derive(Debug, Clone)]
+pub struct Decoder {
+    automaton: Automaton,
+    mode: ScanMode,
+}
+
+impl Decoder {
+    pub fn new(vocabulary: &Vocabulary, mode: ScanMode) -> Decoder {
+        Decoder {
+            automaton: Automaton::new(vocabulary),
+            mode,
+        }
+    }
+
+    /// Digits found in `line`, in the order they appear.
+    pub fn matches(&self, line: &str) -> Vec<Match> {
+        self.automaton.find(line, self.mode)
+    }
+
+    /// The first and last digit of `line` combined, or `None` if it has no digits.
+    pub fn decode_line(&self, line: &str) -> Option<u32> {
+        let matches = self.matches(line);
+
+        Some(matches.first()?.value * 10 + matches.last()?.value)
+    }
+
+    /// Sum of the values of every line; lines without digits add nothing.
+    pub fn decode(&self, input: &str) -> u64 {
+        input
+            .lines()
+            .filter_map(|line| self.decode_line(line))
+            .map(u64::from)
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn digits_only() {
+        let decoder = Decoder::new(&Vocabulary::digits(), ScanMode::Overlapping);
+
+        assert_eq!(
+            decoder.decode("1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet"),
+            142
+        );
+    }
+
+    #[test]
+    fn spelled_out_digits() {
+        let decoder = Decoder::new(&Vocabulary::english(), ScanMode::Overlapping);
+
+        assert_eq!(decoder.decode_line("eightwothree"), Some(83));
+        assert_eq!(
+            decoder.decode("two1nine\neightwothree\nabcone2threexyz\nxtwone3four\n4nineeightseven2\nzoneight234\n7pqrstsixteen"),
+            281
+        );
+        assert_eq!(decoder.decode_line("abc"), None);
+    }
+
+    #[test]
+    fn scan_modes_differ_on_shared_letters() {
+        let vocabulary = Vocabulary::english();
+        let overlapping = Decoder::new(&vocabulary, ScanMode::Overlapping);
+        let non_overlapping = Decoder::new(&vocabulary, ScanMode::NonOverlapping);
+
+        assert_eq!(overlapping.decode_line("1oneight"), Some(18));
+        assert_eq!(non_overlapping.decode_line("1oneight"), Some(11));
+    }
+
+    #[test]
+    fn custom_vocabulary() {
+        let vocabulary = Vocabulary::parse(
+            "eins=1\nzwei=2\ndrei=3\nvier=4\nfünf=5\nsechs=6\nsieben=7\nacht=8\nneun=9",
+        )
+        .unwrap();
+        let decoder = Decoder::new(&vocabulary, ScanMode::Overlapping);
+
+        assert_eq!(decoder.decode_line("xfünfzigsiebenx"), Some(57));
+        assert_eq!(decoder.decode_line("1 2"), None);
+    }
+
+    #[test]
+    fn roman_numerals() {
+        let decoder = Decoder::new(&Vocabulary::roman(), ScanMode::NonOverlapping);
+
+        // Longest match wins, so "viii" is not read as "v" followed by "iii"
+        assert_eq!(decoder.decode_line("-viii-iv-"), Some(84));
+        assert_eq!(decoder.decode_line("ix"), Some(99));
+    }
+}
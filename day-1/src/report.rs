@@ -0,0 +1,126 @@
+use crate::{Decoder, Match};
+use std::fmt;
+
+/// How one line of the calibration document was decoded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineReport {
+    /// Line number, counting from 1.
+    pub number: usize,
+    /// Every word found, with the text it matched.
+    pub matches: Vec<(Match, String)>,
+    pub value: u32,
+}
+
+impl LineReport {
+    pub fn first(&self) -> Option<&Match> {
+        self.matches.first().map(|(m, _)| m)
+    }
+
+    pub fn last(&self) -> Option<&Match> {
+        self.matches.last().map(|(m, _)| m)
+    }
+
+    /// Why the line adds nothing to the total, if it doesn't.
+    pub fn warning(&self) -> Option<&'static str> {
+        if self.matches.is_empty() {
+            Some("no digits found")
+        } else if self.value == 0 {
+            Some("first and last digits are both 0")
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Display for LineReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let found = self
+            .matches
+            .iter()
+            .map(|(m, text)| format!("{:?} ({}) at {}..{}", text, m.value, m.start, m.end))
+            .collect::<Vec<_>>();
+
+        writeln!(f, "Line {}: [{}]", self.number, found.join(", "))?;
+
+        if let (Some(first), Some(last)) = (self.first(), self.last()) {
+            writeln!(
+                f,
+                "  first {}, last {}, value {}",
+                first.value, last.value, self.value
+            )?;
+        }
+
+        if let Some(warning) = self.warning() {
+            writeln!(f, "  warning: {}, contributes 0", warning)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Decoder {
+    pub fn report_line(&self, number: usize, line: &str) -> LineReport {
+        let chars = line.chars().collect::<Vec<_>>();
+        let matches = self
+            .matches(line)
+            .into_iter()
+            .map(|m| (m, chars[m.start..m.end].iter().collect()))
+            .collect();
+
+        LineReport {
+            number,
+            matches,
+            value: self.decode_line(line).unwrap_or(0),
+        }
+    }
+
+    pub fn report(&self, input: &str) -> Vec<LineReport> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| self.report_line(i + 1, line))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Decoder, ScanMode, Vocabulary};
+
+    fn decoder() -> Decoder {
+        Decoder::new(&Vocabulary::english(), ScanMode::Overlapping)
+    }
+
+    #[test]
+    fn reports_words_and_positions() {
+        let report = decoder().report_line(2, "xtwone3four");
+
+        assert_eq!(report.value, 24);
+        assert_eq!(report.first().map(|m| m.start), Some(1));
+        assert_eq!(report.last().map(|m| m.value), Some(4));
+        assert_eq!(report.warning(), None);
+        assert_eq!(
+            report.to_string(),
+            "Line 2: [\"two\" (2) at 1..4, \"one\" (1) at 3..6, \"3\" (3) at 6..7, \"four\" (4) at 7..11]
+  first 2, last 4, value 24
+"
+        );
+    }
+
+    #[test]
+    fn warns_on_lines_that_add_nothing() {
+        let reports = decoder().report("abc\nzero0\n7");
+
+        assert_eq!(reports[0].warning(), Some("no digits found"));
+        assert_eq!(
+            reports[0].to_string(),
+            "Line 1: []\n  warning: no digits found, contributes 0\n"
+        );
+        assert_eq!(
+            reports[1].warning(),
+            Some("first and last digits are both 0")
+        );
+        assert_eq!(reports[2].value, 77);
+        assert_eq!(reports[2].warning(), None);
+    }
+}
@@ -0,0 +1,199 @@
+use crate::{Decoder, Match, ScanMode};
+use std::io::{self, BufRead};
+
+/// The digits chosen so far on the current line, reset at every newline.
+struct Line {
+    chars: usize,
+    first: Option<Match>,
+    last: Option<Match>,
+    /// Non-overlapping candidates that a longer or earlier word might still beat.
+    pending: Vec<Match>,
+    /// End of the last accepted non-overlapping match.
+    taken: usize,
+}
+
+impl Line {
+    fn accept(&mut self, m: Match) {
+        if self.first.is_none() {
+            self.first = Some(m);
+        }
+        self.last = Some(m);
+    }
+
+    /// Keeps the earliest match, and the longest one among those starting at the same place,
+    /// matching the order of `Automaton::find`.
+    fn offer_overlapping(&mut self, m: Match) {
+        let earlier = |a: &Match, b: &Match| (a.start, b.end) < (b.start, a.end);
+
+        if self.first.is_none_or(|first| earlier(&m, &first)) {
+            self.first = Some(m);
+        }
+        if self.last.is_none_or(|last| !earlier(&m, &last)) {
+            self.last = Some(m);
+        }
+    }
+
+    /// Settles pending candidates starting before `until`, which no later word can beat.
+    fn settle(&mut self, until: usize) {
+        self.pending
+            .sort_unstable_by_key(|m| (m.start, std::cmp::Reverse(m.end)));
+
+        let settled = self.pending.partition_point(|m| m.start < until);
+
+        for i in 0..settled {
+            let m = self.pending[i];
+
+            if m.start >= self.taken {
+                self.taken = m.end;
+                self.accept(m);
+            }
+        }
+
+        self.pending.drain(..settled);
+    }
+
+    /// The line's value, leaving the line ready for the next one.
+    fn finish(&mut self) -> u64 {
+        self.settle(usize::MAX);
+
+        let value = match (self.first, self.last) {
+            (Some(first), Some(last)) => first.value as u64 * 10 + last.value as u64,
+            _ => 0,
+        };
+
+        self.chars = 0;
+        self.first = None;
+        self.last = None;
+        self.taken = 0;
+
+        value
+    }
+}
+
+impl Decoder {
+    /// Sum of the values of every line read from `reader`, one buffer at a time.
+    pub fn decode_reader(&self, mut reader: impl BufRead) -> io::Result<u64> {
+        let automaton = &self.automaton;
+        let mut state = automaton.start();
+        let mut sum = 0;
+        let mut line = Line {
+            chars: 0,
+            first: None,
+            last: None,
+            pending: vec![],
+            taken: 0,
+        };
+
+        loop {
+            let buffer = reader.fill_buf()?;
+
+            if buffer.is_empty() {
+                break;
+            }
+
+            for &b in buffer {
+                if b == b'\n' {
+                    sum += line.finish();
+                    state = automaton.start();
+                    continue;
+                }
+
+                state = automaton.step(state, b);
+
+                // Only the first byte of a character starts a new one
+                if b & 0xc0 != 0x80 {
+                    line.chars += 1;
+                }
+
+                let end = line.chars;
+                let mode = self.mode;
+
+                automaton.words_at(state, |len, value| {
+                    let m = Match {
+                        start: end - len,
+                        end,
+                        value,
+                    };
+
+                    match mode {
+                        ScanMode::Overlapping => line.offer_overlapping(m),
+                        ScanMode::NonOverlapping => line.pending.push(m),
+                    }
+                });
+
+                if mode == ScanMode::NonOverlapping {
+                    // The current character may still be incomplete, so only words that
+                    // start early enough to have ended before it have all been seen
+                    line.settle(end.saturating_sub(automaton.longest()));
+                }
+            }
+
+            let len = buffer.len();
+            reader.consume(len);
+        }
+
+        Ok(sum + line.finish())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Decoder, ScanMode, Vocabulary};
+    use std::io::BufReader;
+
+    const INPUT: &str = include_str!("./bin/input-2.txt");
+
+    #[test]
+    fn matches_line_decoder_on_puzzle_input() {
+        for mode in [ScanMode::Overlapping, ScanMode::NonOverlapping] {
+            for vocabulary in [Vocabulary::digits(), Vocabulary::english()] {
+                let decoder = Decoder::new(&vocabulary, mode);
+
+                assert_eq!(
+                    decoder.decode_reader(INPUT.as_bytes()).unwrap(),
+                    decoder.decode(INPUT)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn lines_split_across_small_buffers() {
+        let decoder = Decoder::new(&Vocabulary::english(), ScanMode::Overlapping);
+        let reader = BufReader::with_capacity(3, "two1nine\r\nxtwone3four\nzoneight".as_bytes());
+
+        assert_eq!(decoder.decode_reader(reader).unwrap(), 29 + 24 + 18);
+    }
+
+    #[test]
+    fn non_overlapping_prefers_longest_word() {
+        let mut vocabulary = Vocabulary::roman();
+        vocabulary.add("seventeen", 7).unwrap();
+        vocabulary.add("teen", 1).unwrap();
+        let decoder = Decoder::new(&vocabulary, ScanMode::NonOverlapping);
+
+        for line in ["-viii-iv-", "ix", "seventeeniv", "xteenvii"] {
+            assert_eq!(
+                decoder.decode_reader(line.as_bytes()).unwrap(),
+                decoder.decode(line)
+            );
+        }
+        assert_eq!(decoder.decode_reader("".as_bytes()).unwrap(), 0);
+    }
+
+    #[test]
+    fn non_overlapping_waits_for_whole_characters() {
+        let vocabulary = Vocabulary::parse("tr=1\ntré=3\nx=9").unwrap();
+        let decoder = Decoder::new(&vocabulary, ScanMode::NonOverlapping);
+
+        assert_eq!(decoder.decode_reader("tré".as_bytes()).unwrap(), 33);
+        assert_eq!(decoder.decode_reader("xtré".as_bytes()).unwrap(), 93);
+
+        for line in ["tré", "xtré", "trtrétr", "éxtréé"] {
+            assert_eq!(
+                decoder.decode_reader(line.as_bytes()).unwrap(),
+                decoder.decode(line)
+            );
+        }
+    }
+}
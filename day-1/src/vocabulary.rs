@@ -0,0 +1,163 @@
+/// Words that stand for a digit, together with the digit they stand for.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Vocabulary {
+    words: Vec<(String, u32)>,
+}
+
+const ENGLISH: [&str; 10] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+const ROMAN: [&str; 9] = ["i", "ii", "iii", "iv", "v", "vi", "vii", "viii", "ix"];
+
+impl Vocabulary {
+    pub fn new() -> Vocabulary {
+        Vocabulary::default()
+    }
+
+    /// The characters `0` to `9`, as in part one.
+    pub fn digits() -> Vocabulary {
+        let mut vocabulary = Vocabulary::new();
+
+        for value in 0..10 {
+            vocabulary.add(&value.to_string(), value).unwrap();
+        }
+
+        vocabulary
+    }
+
+    /// Digits plus their English names, as in part two.
+    pub fn english() -> Vocabulary {
+        let mut vocabulary = Vocabulary::digits();
+
+        for (value, word) in ENGLISH.iter().enumerate() {
+            vocabulary.add(word, value as u32).unwrap();
+        }
+
+        vocabulary
+    }
+
+    /// Lower-case roman numerals from 1 to 9.
+    pub fn roman() -> Vocabulary {
+        let mut vocabulary = Vocabulary::new();
+
+        for (value, word) in ROMAN.iter().enumerate() {
+            vocabulary.add(word, value as u32 + 1).unwrap();
+        }
+
+        vocabulary
+    }
+
+    /// Reads one `word=digit` pair per line, skipping blank lines.
+    pub fn parse(input: &str) -> Result<Vocabulary, String> {
+        let mut vocabulary = Vocabulary::new();
+
+        for (i, line) in input.lines().enumerate() {
+            let line = line.trim();
+
+            if line.is_empty() {
+                continue;
+            }
+
+            let (word, value) = line
+                .split_once('=')
+                .ok_or(format!("Missing '=' on line {}", i + 1))?;
+            let value = value
+                .trim()
+                .parse::<u32>()
+                .map_err(|_| format!("Invalid digit: {} on line {}", value.trim(), i + 1))?;
+
+            vocabulary
+                .add(word.trim(), value)
+                .map_err(|err| format!("{} on line {}", err, i + 1))?;
+        }
+
+        Ok(vocabulary)
+    }
+
+    /// One of the built-in vocabularies `digits`, `english` and `roman`, or else the
+    /// `word=digit` pairs in the file at `name`.
+    pub fn load(name: &str) -> Result<Vocabulary, String> {
+        match name {
+            "digits" => Ok(Vocabulary::digits()),
+            "english" => Ok(Vocabulary::english()),
+            "roman" => Ok(Vocabulary::roman()),
+            path => Vocabulary::parse(
+                &std::fs::read_to_string(path).map_err(|err| format!("{}: {}", path, err))?,
+            ),
+        }
+    }
+
+    pub fn add(&mut self, word: &str, value: u32) -> Result<(), String> {
+        if word.is_empty() {
+            return Err("Empty word".to_owned());
+        }
+
+        if value > 9 {
+            return Err(format!("Invalid digit: {}", value));
+        }
+
+        match self.get(word) {
+            Some(existing) if existing != value => Err(format!(
+                "Word {} means both {} and {}",
+                word, existing, value
+            )),
+            Some(_) => Ok(()),
+            None => {
+                self.words.push((word.to_owned(), value));
+                Ok(())
+            }
+        }
+    }
+
+    pub fn get(&self, word: &str) -> Option<u32> {
+        self.words
+            .iter()
+            .find(|(w, _)| w == word)
+            .map(|(_, value)| *value)
+    }
+
+    pub fn words(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.words
+            .iter()
+            .map(|(word, value)| (word.as_str(), *value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builtin_vocabularies() {
+        assert_eq!(Vocabulary::digits().words().count(), 10);
+        assert_eq!(Vocabulary::english().get("seven"), Some(7));
+        assert_eq!(Vocabulary::english().get("7"), Some(7));
+        assert_eq!(Vocabulary::roman().get("iv"), Some(4));
+    }
+
+    #[test]
+    fn parses_user_vocabulary() {
+        let vocabulary = Vocabulary::parse("uno = 1\n\ndiecinueve=9\n").unwrap();
+
+        assert_eq!(vocabulary.get("uno"), Some(1));
+        assert_eq!(vocabulary.get("diecinueve"), Some(9));
+    }
+
+    #[test]
+    fn rejects_bad_entries() {
+        assert_eq!(
+            Vocabulary::parse("one=1\ntwo").unwrap_err(),
+            "Missing '=' on line 2"
+        );
+        assert_eq!(
+            Vocabulary::parse("ten=10").unwrap_err(),
+            "Invalid digit: 10 on line 1"
+        );
+        assert_eq!(
+            Vocabulary::parse("one=1\none=2").unwrap_err(),
+            "Word one means both 1 and 2 on line 2"
+        );
+        assert!(Vocabulary::parse("=1").is_err());
+    }
+}
@@ -0,0 +1,210 @@
+use crate::pipes::{Grid, Point, Tile, UP};
+use crate::tracer::LoopPath;
+use std::collections::HashSet;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AreaMethod {
+    /// Casts a ray to the left of every tile and counts loop crossings.
+    RayCast,
+    /// Shoelace formula over the loop vertices combined with Pick's theorem.
+    Pick,
+    /// Walks every row once, toggling inside/outside on crossings.
+    Scanline,
+}
+
+impl AreaMethod {
+    pub fn from_name(name: &str) -> Result<AreaMethod, String> {
+        match name {
+            "ray-cast" => Ok(AreaMethod::RayCast),
+            "pick" => Ok(AreaMethod::Pick),
+            "scanline" => Ok(AreaMethod::Scanline),
+            _ => Err(format!("Unknown area method: {}", name)),
+        }
+    }
+}
+
+/// Number of tiles enclosed by `loop_path`.
+pub fn enclosed_tiles(grid: &Grid, loop_path: &LoopPath, method: AreaMethod) -> usize {
+    match method {
+        AreaMethod::RayCast => {
+            let on_loop = loop_path.path.iter().copied().collect::<HashSet<_>>();
+            flood(grid, &on_loop).len()
+        }
+        AreaMethod::Pick => pick(loop_path),
+        AreaMethod::Scanline => scanline(grid, loop_path).len(),
+    }
+}
+
+/// Twice the signed area of the polygon through `vertices`.
+fn shoelace(vertices: &[Point]) -> i64 {
+    vertices
+        .iter()
+        .zip(vertices.iter().cycle().skip(1))
+        .map(|(a, b)| a.x * b.y - b.x * a.y)
+        .sum()
+}
+
+/// Interior tiles via Pick's theorem: `A = i + b / 2 - 1`.
+pub fn pick(loop_path: &LoopPath) -> usize {
+    let twice_area = shoelace(&loop_path.vertices()).unsigned_abs() as usize;
+    let boundary = loop_path.len();
+
+    (twice_area + 2 - boundary) / 2
+}
+
+/// Tiles inside the loop, found by counting crossings of upward-facing pipes per row.
+pub fn scanline(grid: &Grid, loop_path: &LoopPath) -> Vec<Point> {
+    let mut on_loop = grid
+        .iter()
+        .map(|row| vec![false; row.len()])
+        .collect::<Vec<_>>();
+
+    for p in &loop_path.path {
+        on_loop[p.y as usize][p.x as usize] = true;
+    }
+
+    let mut output = vec![];
+
+    for (i, row) in grid.iter().enumerate() {
+        let mut inside = false;
+
+        for (j, tile) in row.iter().enumerate() {
+            if on_loop[i][j] {
+                if tile.opens_to(&UP) {
+                    inside = !inside;
+                }
+            } else if inside {
+                output.push(Point::new(j as i64, i as i64));
+            }
+        }
+    }
+
+    output
+}
+
+pub fn flood(grid: &Grid, loop_path: &HashSet<Point>) -> Vec<Point> {
+    let mut output = vec![];
+
+    for (i, row) in grid.iter().enumerate() {
+        for (j, _) in row.iter().enumerate() {
+            let p = Point::new(j as i64, i as i64);
+            let mut collisions = 0;
+
+            if loop_path.contains(&p) {
+                continue;
+            }
+
+            for pp in (0..j).rev() {
+                let check = Point::new(pp as i64, i as i64);
+
+                let tile = grid.get(i).and_then(|row| row.get(pp)).unwrap();
+
+                if loop_path.contains(&check)
+                    && tile != &Tile::HorizontalPipe
+                    && tile != &Tile::SouthWest
+                    && tile != &Tile::SouthEast
+                {
+                    collisions += 1;
+                }
+            }
+
+            if collisions % 2 == 1 {
+                output.push(p);
+            }
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pipes::Maze;
+    use crate::tracer::trace_loop;
+
+    const METHODS: [AreaMethod; 3] = [AreaMethod::RayCast, AreaMethod::Pick, AreaMethod::Scanline];
+
+    fn all_methods(input: &str) -> Vec<usize> {
+        let maze = Maze::parse(input).unwrap();
+        let loop_path = trace_loop(&maze.grid, &maze.start).unwrap();
+
+        METHODS
+            .iter()
+            .map(|method| enclosed_tiles(&maze.grid, &loop_path, *method))
+            .collect()
+    }
+
+    #[test]
+    fn shoelace_of_square() {
+        let square = [
+            Point::new(0, 0),
+            Point::new(2, 0),
+            Point::new(2, 2),
+            Point::new(0, 2),
+        ];
+        assert_eq!(shoelace(&square), 8);
+    }
+
+    #[test]
+    fn methods_agree_on_small_loop() {
+        let input = "...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........";
+        assert_eq!(all_methods(input), vec![4, 4, 4]);
+    }
+
+    #[test]
+    fn methods_agree_on_squeezed_loop() {
+        let input = "..........
+.S------7.
+.|F----7|.
+.||....||.
+.||....||.
+.|L-7F-J|.
+.|..||..|.
+.L--JL--J.
+..........";
+        assert_eq!(all_methods(input), vec![4, 4, 4]);
+    }
+
+    #[test]
+    fn methods_agree_on_junk_filled_loop() {
+        let input = "FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L";
+        assert_eq!(all_methods(input), vec![10, 10, 10]);
+    }
+
+    #[test]
+    fn methods_agree_on_puzzle_input() {
+        let input = include_str!("./bin/input.txt");
+        assert_eq!(all_methods(input), vec![567, 567, 567]);
+    }
+
+    #[test]
+    fn scanline_matches_flood_tile_for_tile() {
+        let input = include_str!("./bin/input.txt");
+        let maze = Maze::parse(input).unwrap();
+        let loop_path = trace_loop(&maze.grid, &maze.start).unwrap();
+        let on_loop = loop_path.path.iter().copied().collect::<HashSet<_>>();
+
+        assert_eq!(
+            scanline(&maze.grid, &loop_path),
+            flood(&maze.grid, &on_loop)
+        );
+    }
+}
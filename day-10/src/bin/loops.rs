@@ -0,0 +1,64 @@
+use day_10::{area::pick, find_loops, parse_grid, Maze, Point};
+
+/// Start, length and enclosed tile count of every loop in the grid.
+fn process(input: &str) -> Result<Vec<(Point, usize, usize)>, String> {
+    let grid = if input.contains('S') {
+        Maze::parse(input)?.grid
+    } else {
+        parse_grid(input)?
+    };
+
+    Ok(find_loops(&grid)
+        .iter()
+        .map(|loop_path| (loop_path.start(), loop_path.len(), pick(loop_path)))
+        .collect())
+}
+
+fn main() {
+    let input = include_str!("./input.txt");
+    match process(input) {
+        Ok(loops) => {
+            for (i, (start, length, enclosed)) in loops.iter().enumerate() {
+                println!(
+                    "Loop {}: starts at {:?}, length {}, encloses {}",
+                    i + 1,
+                    start,
+                    length,
+                    enclosed
+                );
+            }
+        }
+        Err(err) => println!("Error: {}", err),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn base_case() {
+        let input = "F--7.F7
+|F7|.LJ
+|LJ|...
+L--J...";
+        assert_eq!(
+            process(input).unwrap(),
+            vec![
+                (Point::new(0, 0), 12, 4),
+                (Point::new(5, 0), 4, 0),
+                (Point::new(1, 1), 4, 0)
+            ]
+        );
+    }
+
+    #[test]
+    fn with_starting_point() {
+        let input = "......
+.S--7.
+.|F7|.
+.||||.
+.LJLJ.";
+        assert_eq!(process(input).unwrap(), vec![(Point::new(1, 1), 16, 0)]);
+    }
+}
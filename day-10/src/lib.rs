@@ -0,0 +1,9 @@
+pub mod area;
+pub mod pipes;
+pub mod render;
+pub mod tracer;
+
+pub use area::{enclosed_tiles, AreaMethod};
+pub use pipes::{find_start, infer_start_tile, parse_grid, tile_at, Grid, Maze, Point, Tile};
+pub use render::{render, RenderFormat};
+pub use tracer::{find_loops, trace_loop, LoopPath};
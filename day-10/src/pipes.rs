@@ -0,0 +1,296 @@
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub fn new(x: i64, y: i64) -> Point {
+        Self { x, y }
+    }
+
+    pub fn inverse(&self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+
+    pub fn step(&self, direction: &Point) -> Point {
+        Point::new(self.x + direction.x, self.y + direction.y)
+    }
+}
+
+pub const UP: Point = Point { x: 0, y: -1 };
+pub const DOWN: Point = Point { x: 0, y: 1 };
+pub const LEFT: Point = Point { x: -1, y: 0 };
+pub const RIGHT: Point = Point { x: 1, y: 0 };
+
+pub const DIRECTIONS: [Point; 4] = [RIGHT, DOWN, UP, LEFT];
+
+#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
+pub enum Tile {
+    Ground,
+    VerticalPipe,
+    HorizontalPipe,
+    StartingPoint,
+    NorthWest,
+    NorthEast,
+    SouthWest,
+    SouthEast,
+}
+
+impl Tile {
+    pub fn from_char(c: char) -> Result<Tile, String> {
+        match c {
+            '.' => Ok(Tile::Ground),
+            // Light, heavy, double and rounded box-drawing characters are
+            // accepted as synonyms for the puzzle's ASCII pipes.
+            '|' | '│' | '┃' | '║' => Ok(Tile::VerticalPipe),
+            '-' | '─' | '━' | '═' => Ok(Tile::HorizontalPipe),
+            'F' | '┌' | '┏' | '╔' | '╭' => Ok(Tile::SouthEast),
+            'J' | '┘' | '┛' | '╝' | '╯' => Ok(Tile::NorthWest),
+            'L' | '└' | '┗' | '╚' | '╰' => Ok(Tile::NorthEast),
+            '7' | '┐' | '┓' | '╗' | '╮' => Ok(Tile::SouthWest),
+            'S' => Ok(Tile::StartingPoint),
+            _ => Err(format!("Invalid character: {}", c)),
+        }
+    }
+
+    /// The two directions a pipe leads out of its tile.
+    pub fn openings(&self) -> Option<[Point; 2]> {
+        match self {
+            Tile::VerticalPipe => Some([UP, DOWN]),
+            Tile::HorizontalPipe => Some([LEFT, RIGHT]),
+            Tile::NorthWest => Some([UP, LEFT]),
+            Tile::NorthEast => Some([UP, RIGHT]),
+            Tile::SouthWest => Some([DOWN, LEFT]),
+            Tile::SouthEast => Some([DOWN, RIGHT]),
+            Tile::Ground | Tile::StartingPoint => None,
+        }
+    }
+
+    /// The pipe whose openings are exactly `a` and `b`, in either order.
+    pub fn from_openings(a: &Point, b: &Point) -> Option<Tile> {
+        [
+            Tile::VerticalPipe,
+            Tile::HorizontalPipe,
+            Tile::NorthWest,
+            Tile::NorthEast,
+            Tile::SouthWest,
+            Tile::SouthEast,
+        ]
+        .into_iter()
+        .find(|tile| tile.opens_to(a) && tile.opens_to(b) && a != b)
+    }
+
+    pub fn opens_to(&self, direction: &Point) -> bool {
+        self.openings()
+            .map(|openings| openings.contains(direction))
+            .unwrap_or(false)
+    }
+}
+
+pub type Grid = Vec<Vec<Tile>>;
+
+pub fn parse_grid(input: &str) -> Result<Grid, String> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(y, line)| {
+            line.chars()
+                .enumerate()
+                .map(|(x, c)| {
+                    Tile::from_char(c)
+                        .map_err(|err| format!("{} at line {}, column {}", err, y + 1, x + 1))
+                })
+                .collect()
+        })
+        .collect()
+}
+
+pub fn tile_at(grid: &Grid, p: &Point) -> Option<Tile> {
+    if p.x < 0 || p.y < 0 {
+        return None;
+    }
+
+    grid.get(p.y as usize)
+        .and_then(|row| row.get(p.x as usize))
+        .copied()
+}
+
+pub fn find_start(grid: &Grid) -> Result<Point, String> {
+    let mut starting_point: Option<Point> = None;
+
+    for (y, row) in grid.iter().enumerate() {
+        for (x, tile) in row.iter().enumerate() {
+            if let Tile::StartingPoint = tile {
+                let p = Point::new(x as i64, y as i64);
+
+                if let Some(sp) = starting_point {
+                    return Err(format!(
+                        "Ambiguous starting point: found S at {:?} and {:?}",
+                        sp, p
+                    ));
+                }
+
+                starting_point = Some(p);
+            }
+        }
+    }
+
+    starting_point.ok_or_else(|| "No starting point in grid".to_owned())
+}
+
+/// Directions out of `start` whose neighbour has a pipe leading back into it.
+pub fn connected_directions(grid: &Grid, start: &Point) -> Vec<Point> {
+    DIRECTIONS
+        .iter()
+        .filter(|direction| {
+            tile_at(grid, &start.step(direction))
+                .map(|tile| tile.opens_to(&direction.inverse()))
+                .unwrap_or(false)
+        })
+        .copied()
+        .collect()
+}
+
+/// Works out which pipe is hidden under `S` from the neighbours that connect to it.
+pub fn infer_start_tile(grid: &Grid, start: &Point) -> Result<Tile, String> {
+    match connected_directions(grid, start).as_slice() {
+        [a, b] => Tile::from_openings(a, b)
+            .ok_or_else(|| format!("No pipe connects {:?} and {:?} at {:?}", a, b, start)),
+        connected => Err(format!(
+            "Starting point {:?} connects to {} pipe(s), expected 2",
+            start,
+            connected.len()
+        )),
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Maze {
+    /// The parsed tiles, with `S` replaced by the pipe underneath it.
+    pub grid: Grid,
+    pub start: Point,
+}
+
+impl Maze {
+    pub fn parse(input: &str) -> Result<Maze, String> {
+        let mut grid = parse_grid(input)?;
+        let start = find_start(&grid)?;
+        let start_tile = infer_start_tile(&grid, &start)?;
+
+        grid[start.y as usize][start.x as usize] = start_tile;
+
+        Ok(Maze { grid, start })
+    }
+
+    pub fn start_tile(&self) -> Tile {
+        self.grid[self.start.y as usize][self.start.x as usize]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn infers_start_tile() {
+        let maze = Maze::parse(
+            ".....
+.S-7.
+.|.|.
+.L-J.
+.....",
+        )
+        .unwrap();
+
+        assert_eq!(maze.start, Point::new(1, 1));
+        assert_eq!(maze.start_tile(), Tile::SouthEast);
+    }
+
+    #[test]
+    fn infers_start_tile_next_to_unconnected_pipes() {
+        let maze = Maze::parse(
+            "7-F7-
+.FJ|7
+SJLL7
+|F--J
+LJ.LJ",
+        )
+        .unwrap();
+
+        assert_eq!(maze.start_tile(), Tile::SouthEast);
+    }
+
+    #[test]
+    fn accepts_box_drawing_synonyms() {
+        let ascii = parse_grid(
+            "F-7.
+|.|.
+L-J.",
+        )
+        .unwrap();
+
+        for input in ["┌─┐.\n│.│.\n└─┘.", "╔═╗.\n║.║.\n╚═╝.", "╭━╮.\n┃.┃.\n╰━╯."]
+        {
+            assert_eq!(parse_grid(input).unwrap(), ascii);
+        }
+    }
+
+    #[test]
+    fn rejects_unknown_characters_with_position() {
+        let err = parse_grid(
+            "F-7
+|x|
+L-J",
+        )
+        .unwrap_err();
+
+        assert_eq!(err, "Invalid character: x at line 2, column 2");
+    }
+
+    #[test]
+    fn rejects_missing_start() {
+        let err = Maze::parse(
+            ".F7
+.LJ",
+        )
+        .unwrap_err();
+
+        assert_eq!(err, "No starting point in grid");
+    }
+
+    #[test]
+    fn rejects_multiple_starts() {
+        assert!(Maze::parse(
+            "S-7
+|.|
+L-S",
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn rejects_start_with_too_many_connections() {
+        let err = Maze::parse(
+            ".|.
+-S-
+.|.",
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err,
+            "Starting point Point { x: 1, y: 1 } connects to 4 pipe(s), expected 2"
+        );
+    }
+
+    #[test]
+    fn rejects_start_with_one_connection() {
+        assert!(Maze::parse(
+            "...
+.S-
+...",
+        )
+        .is_err());
+    }
+}
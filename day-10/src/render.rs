@@ -0,0 +1,252 @@
+use crate::area::scanline;
+use crate::pipes::{Grid, Point, Tile};
+use crate::tracer::LoopPath;
+use colored::Colorize;
+use std::fmt::Write;
+
+const CELL_SIZE: usize = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenderFormat {
+    /// Box-drawing characters with ANSI colours, for terminals.
+    Ansi,
+    /// Box-drawing characters without colour.
+    Plain,
+    Svg,
+}
+
+impl RenderFormat {
+    pub fn from_name(name: &str) -> Result<RenderFormat, String> {
+        match name {
+            "ansi" => Ok(RenderFormat::Ansi),
+            "plain" => Ok(RenderFormat::Plain),
+            "svg" => Ok(RenderFormat::Svg),
+            _ => Err(format!("Unknown render format: {}", name)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Cell {
+    Start(Tile),
+    Loop(Tile),
+    Inside,
+    Outside,
+}
+
+fn box_char(tile: &Tile) -> char {
+    match tile {
+        Tile::VerticalPipe => '│',
+        Tile::HorizontalPipe => '─',
+        Tile::NorthWest => '┘',
+        Tile::NorthEast => '└',
+        Tile::SouthWest => '┐',
+        Tile::SouthEast => '┌',
+        Tile::StartingPoint => 'S',
+        Tile::Ground => '.',
+    }
+}
+
+fn classify(grid: &Grid, loop_path: &LoopPath) -> Vec<Vec<Cell>> {
+    let mut cells = grid
+        .iter()
+        .map(|row| vec![Cell::Outside; row.len()])
+        .collect::<Vec<_>>();
+
+    for p in scanline(grid, loop_path) {
+        cells[p.y as usize][p.x as usize] = Cell::Inside;
+    }
+
+    for p in &loop_path.path {
+        cells[p.y as usize][p.x as usize] = Cell::Loop(grid[p.y as usize][p.x as usize]);
+    }
+
+    let start = loop_path.start();
+    cells[start.y as usize][start.x as usize] =
+        Cell::Start(grid[start.y as usize][start.x as usize]);
+
+    cells
+}
+
+/// Draws the loop through `loop_path` along with the tiles it encloses.
+pub fn render(grid: &Grid, loop_path: &LoopPath, format: RenderFormat) -> String {
+    let cells = classify(grid, loop_path);
+
+    match format {
+        RenderFormat::Ansi => render_text(&cells, true),
+        RenderFormat::Plain => render_text(&cells, false),
+        RenderFormat::Svg => render_svg(&cells, loop_path),
+    }
+}
+
+fn render_text(cells: &[Vec<Cell>], coloured: bool) -> String {
+    let mut output = String::new();
+
+    for row in cells {
+        for cell in row {
+            let glyph = match cell {
+                Cell::Start(_) => 'S',
+                Cell::Loop(tile) => box_char(tile),
+                Cell::Inside => 'I',
+                Cell::Outside => 'O',
+            };
+
+            if !coloured {
+                output.push(glyph);
+                continue;
+            }
+
+            let glyph = match cell {
+                Cell::Start(tile) => box_char(tile).to_string().yellow().bold(),
+                Cell::Loop(_) => glyph.to_string().blue(),
+                Cell::Inside => glyph.to_string().green().bold(),
+                Cell::Outside => glyph.to_string().dimmed(),
+            };
+            output.push_str(&glyph.to_string());
+        }
+        output.push('\n');
+    }
+
+    output
+}
+
+fn render_svg(cells: &[Vec<Cell>], loop_path: &LoopPath) -> String {
+    let height = cells.len();
+    let width = cells.iter().map(|row| row.len()).max().unwrap_or(0);
+    let centre = |p: &Point| {
+        (
+            p.x as usize * CELL_SIZE + CELL_SIZE / 2,
+            p.y as usize * CELL_SIZE + CELL_SIZE / 2,
+        )
+    };
+
+    let mut output = String::new();
+
+    writeln!(
+        output,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" viewBox="0 0 {} {}">"#,
+        width * CELL_SIZE,
+        height * CELL_SIZE,
+        width * CELL_SIZE,
+        height * CELL_SIZE
+    )
+    .unwrap();
+    writeln!(
+        output,
+        r##"<rect width="100%" height="100%" fill="#eeeeee"/>"##
+    )
+    .unwrap();
+
+    for (y, row) in cells.iter().enumerate() {
+        for (x, cell) in row.iter().enumerate() {
+            if let Cell::Inside = cell {
+                writeln!(
+                    output,
+                    r##"<rect x="{}" y="{}" width="{}" height="{}" fill="#4caf50"/>"##,
+                    x * CELL_SIZE,
+                    y * CELL_SIZE,
+                    CELL_SIZE,
+                    CELL_SIZE
+                )
+                .unwrap();
+            }
+        }
+    }
+
+    let points = loop_path
+        .vertices()
+        .iter()
+        .map(|p| {
+            let (x, y) = centre(p);
+            format!("{},{}", x, y)
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    writeln!(
+        output,
+        r##"<polygon points="{}" fill="none" stroke="#1e50b4" stroke-width="2"/>"##,
+        points
+    )
+    .unwrap();
+
+    let (x, y) = centre(&loop_path.start());
+    writeln!(
+        output,
+        r##"<circle cx="{}" cy="{}" r="{}" fill="#f0b400"/>"##,
+        x,
+        y,
+        CELL_SIZE / 2
+    )
+    .unwrap();
+    output.push_str("</svg>\n");
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pipes::Maze;
+    use crate::tracer::trace_loop;
+
+    fn render_input(input: &str, format: RenderFormat) -> String {
+        let maze = Maze::parse(input).unwrap();
+        let loop_path = trace_loop(&maze.grid, &maze.start).unwrap();
+
+        render(&maze.grid, &loop_path, format)
+    }
+
+    #[test]
+    fn plain_rendering() {
+        let input = "-L|F7
+7S-7|
+L|.||
+-L-J|
+L|-JF";
+        assert_eq!(
+            render_input(input, RenderFormat::Plain),
+            "OOOOO
+OS─┐O
+O│I│O
+O└─┘O
+OOOOO
+"
+        );
+    }
+
+    #[test]
+    fn ansi_rendering_keeps_glyphs() {
+        colored::control::set_override(true);
+
+        let output = render_input(
+            ".....
+.S-7.
+.|.|.
+.L-J.
+.....",
+            RenderFormat::Ansi,
+        );
+
+        assert!(output.contains("\u{1b}["));
+        assert!(output.contains('┌'));
+        assert!(output.contains('I'));
+    }
+
+    #[test]
+    fn svg_rendering() {
+        let output = render_input(
+            ".....
+.S-7.
+.|.|.
+.L-J.
+.....",
+            RenderFormat::Svg,
+        );
+
+        assert!(output.starts_with("<svg"));
+        assert!(output.contains(r#"<polygon points="15,15 15,35 35,35 35,15""#));
+        assert_eq!(output.matches("#4caf50").count(), 1);
+        assert!(output.ends_with("</svg>\n"));
+    }
+}
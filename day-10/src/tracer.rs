@@ -0,0 +1,266 @@
+use crate::pipes::{tile_at, Grid, Point};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoopPath {
+    /// Every tile of the loop in walking order, starting at `S`.
+    pub path: Vec<Point>,
+}
+
+impl LoopPath {
+    pub fn len(&self) -> usize {
+        self.path.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.path.is_empty()
+    }
+
+    pub fn start(&self) -> Point {
+        self.path[0]
+    }
+
+    /// Steps from `S` to the point farthest along the loop in either direction.
+    pub fn farthest_distance(&self) -> usize {
+        self.path.len() / 2
+    }
+
+    pub fn farthest_point(&self) -> Point {
+        self.path[self.farthest_distance()]
+    }
+
+    /// The corners of the loop, in walking order.
+    pub fn vertices(&self) -> Vec<Point> {
+        let n = self.path.len();
+
+        (0..n)
+            .filter(|i| {
+                let prev = self.path[(i + n - 1) % n];
+                let next = self.path[(i + 1) % n];
+                prev.x != next.x && prev.y != next.y
+            })
+            .map(|i| self.path[i])
+            .collect()
+    }
+}
+
+/// Follows the loop through `start` once, without recursion.
+///
+/// `start` must already hold its real pipe rather than `S`, see [`crate::Maze`].
+pub fn trace_loop(grid: &Grid, start: &Point) -> Result<LoopPath, String> {
+    let mut seen = unseen(grid);
+
+    follow(grid, start, &mut seen)
+}
+
+/// Every closed loop in the grid, ordered by the first tile of each in reading order.
+pub fn find_loops(grid: &Grid) -> Vec<LoopPath> {
+    let mut seen = unseen(grid);
+    let mut loops = vec![];
+
+    for (y, row) in grid.iter().enumerate() {
+        for (x, tile) in row.iter().enumerate() {
+            if seen[y][x] || tile.openings().is_none() {
+                continue;
+            }
+
+            if let Ok(loop_path) = follow(grid, &Point::new(x as i64, y as i64), &mut seen) {
+                loops.push(loop_path);
+            }
+        }
+    }
+
+    loops
+}
+
+fn unseen(grid: &Grid) -> Vec<Vec<bool>> {
+    grid.iter().map(|row| vec![false; row.len()]).collect()
+}
+
+/// Walks from `start` until it gets back there, marking every tile it passes in `seen`.
+///
+/// Running into a tile that is already marked means `start` is not on a loop, or
+/// that the loop was traced before.
+fn follow(grid: &Grid, start: &Point, seen: &mut [Vec<bool>]) -> Result<LoopPath, String> {
+    let start_tile = tile_at(grid, start)
+        .ok_or_else(|| format!("Starting point {:?} is outside the grid", start))?;
+    let [heading, _] = start_tile
+        .openings()
+        .ok_or_else(|| format!("No pipe at the starting point {:?}", start))?;
+
+    let mut path = vec![*start];
+    let mut heading = heading;
+    let mut current = start.step(&heading);
+
+    seen[start.y as usize][start.x as usize] = true;
+
+    while current != *start {
+        let tile = tile_at(grid, &current)
+            .ok_or_else(|| format!("Loop leaves the grid at {:?}", current))?;
+        let openings = tile
+            .openings()
+            .filter(|openings| openings.contains(&heading.inverse()))
+            .ok_or_else(|| format!("Loop is broken at {:?}", current))?;
+
+        if seen[current.y as usize][current.x as usize] {
+            return Err(format!("Loop runs into a traced pipe at {:?}", current));
+        }
+
+        seen[current.y as usize][current.x as usize] = true;
+        path.push(current);
+
+        heading = if openings[0] == heading.inverse() {
+            openings[1]
+        } else {
+            openings[0]
+        };
+        current = current.step(&heading);
+    }
+
+    if !start_tile.opens_to(&heading.inverse()) {
+        return Err(format!("Loop is broken at {:?}", start));
+    }
+
+    Ok(LoopPath { path })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pipes::{parse_grid, Maze};
+
+    #[test]
+    fn traces_simple_loop() {
+        let maze = Maze::parse(
+            ".....
+.S-7.
+.|.|.
+.L-J.
+.....",
+        )
+        .unwrap();
+        let loop_path = trace_loop(&maze.grid, &maze.start).unwrap();
+
+        assert_eq!(loop_path.len(), 8);
+        assert_eq!(loop_path.start(), Point::new(1, 1));
+        assert_eq!(loop_path.farthest_distance(), 4);
+        assert_eq!(loop_path.farthest_point(), Point::new(3, 3));
+        assert_eq!(
+            loop_path.vertices(),
+            vec![
+                Point::new(1, 1),
+                Point::new(1, 3),
+                Point::new(3, 3),
+                Point::new(3, 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn ignores_pipes_not_connected_to_start() {
+        let maze = Maze::parse(
+            "-L|F7
+7S-7|
+L|7||
+-L-J|
+L|-JF",
+        )
+        .unwrap();
+        let loop_path = trace_loop(&maze.grid, &maze.start).unwrap();
+
+        assert_eq!(loop_path.len(), 8);
+    }
+
+    #[test]
+    fn handles_very_long_loops() {
+        let width = 2000;
+        let height = 1000;
+        let mut input = String::new();
+
+        for y in 0..height {
+            for x in 0..width {
+                let c = match (x, y) {
+                    (0, 0) => 'S',
+                    (x, 0) if x == width - 1 => '7',
+                    (0, y) if y == height - 1 => 'L',
+                    (x, y) if x == width - 1 && y == height - 1 => 'J',
+                    (_, 0) => '-',
+                    (_, y) if y == height - 1 => '-',
+                    _ if x == 0 || x == width - 1 => '|',
+                    _ => '.',
+                };
+                input.push(c);
+            }
+            input.push('\n');
+        }
+
+        let maze = Maze::parse(&input).unwrap();
+        let loop_path = trace_loop(&maze.grid, &maze.start).unwrap();
+
+        assert_eq!(loop_path.len(), 2 * (width + height) - 4);
+    }
+
+    #[test]
+    fn rejects_unresolved_start() {
+        let grid = parse_grid(
+            ".F7
+.SJ",
+        )
+        .unwrap();
+
+        assert!(trace_loop(&grid, &Point::new(1, 1)).is_err());
+    }
+
+    #[test]
+    fn rejects_loop_that_does_not_close_at_start() {
+        let grid = parse_grid(
+            "F-7
+|.|
+L-J",
+        )
+        .unwrap();
+
+        assert!(trace_loop(&grid, &Point::new(1, 0)).is_ok());
+        assert!(trace_loop(&grid, &Point::new(1, 1)).is_err());
+    }
+
+    #[test]
+    fn finds_every_loop() {
+        let grid = parse_grid(
+            "F7.F--7
+LJ.|F7|
+...|LJ|
+F-7L--J
+L-J.-|.",
+        )
+        .unwrap();
+        let loops = find_loops(&grid);
+
+        assert_eq!(
+            loops
+                .iter()
+                .map(|l| (l.start(), l.len()))
+                .collect::<Vec<_>>(),
+            vec![
+                (Point::new(0, 0), 4),
+                (Point::new(3, 0), 12),
+                (Point::new(4, 1), 4),
+                (Point::new(0, 3), 6)
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_broken_loop() {
+        let grid = parse_grid(
+            "F-7
+|.|
+L-.",
+        )
+        .unwrap();
+
+        assert_eq!(
+            trace_loop(&grid, &Point::new(0, 0)).unwrap_err(),
+            "Loop is broken at Point { x: 2, y: 2 }"
+        );
+    }
+}
@@ -0,0 +1,230 @@
+use crate::expansion::Universe;
+use crate::Point;
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Metric {
+    /// Steps up, down, left and right, as in the puzzle.
+    Manhattan,
+    /// Steps in any of the eight directions.
+    Chebyshev,
+    /// Straight-line distance.
+    Euclidean,
+}
+
+/// A distance under some [`Metric`]: whole steps on the grid, or a straight line.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub enum Distance {
+    Steps(u128),
+    Straight(f64),
+}
+
+impl fmt::Display for Distance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Distance::Steps(steps) => write!(f, "{}", steps),
+            Distance::Straight(length) => write!(f, "{}", length),
+        }
+    }
+}
+
+impl Metric {
+    pub fn distance(&self, a: &Point, b: &Point) -> Distance {
+        let dx = a.x.abs_diff(b.x);
+        let dy = a.y.abs_diff(b.y);
+
+        match self {
+            Metric::Manhattan => Distance::Steps(dx + dy),
+            Metric::Chebyshev => Distance::Steps(dx.max(dy)),
+            Metric::Euclidean => Distance::Straight((dx as f64).hypot(dy as f64)),
+        }
+    }
+}
+
+impl Universe {
+    /// Distance between galaxies `a` and `b`, numbered from 1 in reading order.
+    pub fn distance(&self, a: usize, b: usize, metric: Metric) -> Option<Distance> {
+        Some(metric.distance(&self.galaxy(a)?, &self.galaxy(b)?))
+    }
+
+    /// Distances from galaxy `number` to every other galaxy, as `(other number, distance)`.
+    fn distances_from(&self, number: usize, metric: Metric) -> Option<Vec<(usize, Distance)>> {
+        let from = self.galaxy(number)?;
+
+        Some(
+            self.galaxies
+                .iter()
+                .enumerate()
+                .map(|(i, g)| (i + 1, metric.distance(&from, g)))
+                .filter(|(other, _)| *other != number)
+                .collect(),
+        )
+    }
+
+    /// The closest other galaxy to `number`, preferring the lowest number on ties.
+    pub fn nearest_neighbour(&self, number: usize, metric: Metric) -> Option<(usize, Distance)> {
+        self.distances_from(number, metric)?
+            .into_iter()
+            .reduce(|best, next| if next.1 < best.1 { next } else { best })
+    }
+
+    /// The most distant other galaxy from `number`, preferring the lowest number on ties.
+    pub fn farthest_neighbour(&self, number: usize, metric: Metric) -> Option<(usize, Distance)> {
+        self.distances_from(number, metric)?
+            .into_iter()
+            .reduce(|best, next| if next.1 > best.1 { next } else { best })
+    }
+
+    /// Every pairwise distance as CSV, with galaxy numbers as row and column headers.
+    pub fn distance_matrix_csv(&self, metric: Metric) -> String {
+        let mut output = String::new();
+
+        for i in 1..=self.galaxies.len() {
+            output.push(',');
+            output.push_str(&i.to_string());
+        }
+        output.push('\n');
+
+        for (i, a) in self.galaxies.iter().enumerate() {
+            output.push_str(&(i + 1).to_string());
+
+            for b in &self.galaxies {
+                output.push(',');
+                output.push_str(&metric.distance(a, b).to_string());
+            }
+            output.push('\n');
+        }
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expansion::Expansion;
+    use crate::map::GalaxyMap;
+    use crate::tests::INPUT;
+
+    fn universe() -> Universe {
+        Universe::new(&GalaxyMap::parse(INPUT).unwrap(), &Expansion::uniform(2)).unwrap()
+    }
+
+    #[test]
+    fn puzzle_distances() {
+        let universe = universe();
+
+        assert_eq!(
+            universe.distance(5, 9, Metric::Manhattan),
+            Some(Distance::Steps(9))
+        );
+        assert_eq!(
+            universe.distance(1, 7, Metric::Manhattan),
+            Some(Distance::Steps(15))
+        );
+        assert_eq!(
+            universe.distance(3, 6, Metric::Manhattan),
+            Some(Distance::Steps(17))
+        );
+        assert_eq!(
+            universe.distance(8, 9, Metric::Manhattan),
+            Some(Distance::Steps(5))
+        );
+        assert_eq!(universe.distance(8, 10, Metric::Manhattan), None);
+    }
+
+    #[test]
+    fn alternative_metrics() {
+        let universe = universe();
+
+        assert_eq!(
+            universe.distance(5, 9, Metric::Chebyshev),
+            Some(Distance::Steps(5))
+        );
+        assert_eq!(
+            universe.distance(8, 9, Metric::Euclidean),
+            Some(Distance::Straight(5.0))
+        );
+        assert_eq!(
+            universe.distance(5, 9, Metric::Euclidean),
+            Some(Distance::Straight(41f64.sqrt()))
+        );
+    }
+
+    #[test]
+    fn exact_steps_beyond_float_precision() {
+        let far = Point::new(1 << 53, 1);
+
+        assert_eq!(
+            Metric::Manhattan.distance(&Point::new(0, 0), &far),
+            Distance::Steps((1 << 53) + 1)
+        );
+    }
+
+    #[test]
+    fn steps_beyond_u64() {
+        let universe = Universe::new(
+            &GalaxyMap::parse("#.#").unwrap(),
+            &Expansion::uniform(1 << 70),
+        )
+        .unwrap();
+
+        assert_eq!(
+            universe.distance(1, 2, Metric::Manhattan),
+            Some(Distance::Steps((1 << 70) + 1))
+        );
+        assert_eq!(
+            universe
+                .distance(1, 2, Metric::Manhattan)
+                .unwrap()
+                .to_string(),
+            universe.sum_of_distances().to_string()
+        );
+    }
+
+    #[test]
+    fn neighbours() {
+        let universe = universe();
+
+        assert_eq!(
+            universe.nearest_neighbour(8, Metric::Manhattan),
+            Some((9, Distance::Steps(5)))
+        );
+        assert_eq!(
+            universe.farthest_neighbour(8, Metric::Manhattan),
+            Some((2, Distance::Steps(19)))
+        );
+        assert_eq!(universe.nearest_neighbour(11, Metric::Manhattan), None);
+    }
+
+    #[test]
+    fn neighbours_of_lonely_galaxy() {
+        let universe =
+            Universe::new(&GalaxyMap::parse("..#").unwrap(), &Expansion::uniform(2)).unwrap();
+
+        assert_eq!(universe.nearest_neighbour(1, Metric::Manhattan), None);
+    }
+
+    #[test]
+    fn csv_matrix() {
+        let universe = Universe::new(
+            &GalaxyMap::parse(
+                "#.#
+...
+#..",
+            )
+            .unwrap(),
+            &Expansion::uniform(2),
+        )
+        .unwrap();
+
+        assert_eq!(
+            universe.distance_matrix_csv(Metric::Manhattan),
+            ",1,2,3
+1,0,3,3
+2,3,0,6
+3,3,6,0
+"
+        );
+    }
+}
@@ -0,0 +1,200 @@
+use crate::map::GalaxyMap;
+use crate::{sum_of_distances, Point};
+
+/// How far the empty lines along one axis grow.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AxisExpansion {
+    /// Every empty line becomes this many lines.
+    Uniform(i128),
+    /// The n-th empty line, counted from the top or left, becomes `factors[n]` lines.
+    PerLine(Vec<i128>),
+}
+
+impl AxisExpansion {
+    /// Extra space added before each coordinate in `0..len`, given the empty lines of the axis.
+    fn offsets(&self, len: usize, empty: &[usize]) -> Result<Vec<i128>, String> {
+        let factors = match self {
+            AxisExpansion::Uniform(factor) => vec![*factor; empty.len()],
+            AxisExpansion::PerLine(factors) if factors.len() == empty.len() => factors.clone(),
+            AxisExpansion::PerLine(factors) => {
+                return Err(format!(
+                    "Got {} expansion factors for {} empty lines",
+                    factors.len(),
+                    empty.len()
+                ))
+            }
+        };
+
+        if let Some(factor) = factors.iter().find(|f| **f < 1) {
+            return Err(format!("Invalid expansion factor: {}", factor));
+        }
+
+        let mut offsets = vec![0; len];
+        let mut extra = 0;
+        let mut next = empty.iter().zip(factors.iter()).peekable();
+
+        for (i, offset) in offsets.iter_mut().enumerate() {
+            *offset = extra;
+
+            if let Some((_, factor)) = next.next_if(|(line, _)| **line == i) {
+                extra += factor - 1;
+            }
+        }
+
+        Ok(offsets)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expansion {
+    pub rows: AxisExpansion,
+    pub columns: AxisExpansion,
+}
+
+impl Expansion {
+    /// The puzzle's expansion: empty rows and columns alike become `factor` lines.
+    pub fn uniform(factor: i128) -> Expansion {
+        Expansion {
+            rows: AxisExpansion::Uniform(factor),
+            columns: AxisExpansion::Uniform(factor),
+        }
+    }
+
+    pub fn new(rows: AxisExpansion, columns: AxisExpansion) -> Expansion {
+        Expansion { rows, columns }
+    }
+}
+
+/// The galaxies of a map after its empty space has expanded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Universe {
+    /// Expanded galaxy positions in reading order of the original map.
+    pub galaxies: Vec<Point>,
+    column_offsets: Vec<i128>,
+    row_offsets: Vec<i128>,
+}
+
+impl Universe {
+    pub fn new(map: &GalaxyMap, expansion: &Expansion) -> Result<Universe, String> {
+        let column_offsets = expansion
+            .columns
+            .offsets(map.columns, &map.empty_columns())?;
+        let row_offsets = expansion.rows.offsets(map.rows, &map.empty_rows())?;
+
+        let galaxies = map
+            .galaxies()
+            .iter()
+            .map(|g| {
+                Point::new(
+                    g.x + column_offsets[g.x as usize],
+                    g.y + row_offsets[g.y as usize],
+                )
+            })
+            .collect();
+
+        Ok(Universe {
+            galaxies,
+            column_offsets,
+            row_offsets,
+        })
+    }
+
+    /// Expanded position of galaxy `number`, counting from 1 in reading order.
+    pub fn galaxy(&self, number: usize) -> Option<Point> {
+        number
+            .checked_sub(1)
+            .and_then(|i| self.galaxies.get(i))
+            .copied()
+    }
+
+    /// Where the tile at `p` on the original map ends up after expansion.
+    pub fn expand_point(&self, p: &Point) -> Option<Point> {
+        let x = self.column_offsets.get(usize::try_from(p.x).ok()?)?;
+        let y = self.row_offsets.get(usize::try_from(p.y).ok()?)?;
+
+        Some(Point::new(p.x + x, p.y + y))
+    }
+
+    pub fn sum_of_distances(&self) -> i128 {
+        sum_of_distances(&self.galaxies)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::INPUT;
+
+    fn universe(expansion: Expansion) -> Result<Universe, String> {
+        Universe::new(&GalaxyMap::parse(INPUT).unwrap(), &expansion)
+    }
+
+    #[test]
+    fn uniform_expansion_matches_puzzle() {
+        assert_eq!(
+            universe(Expansion::uniform(2)).unwrap().sum_of_distances(),
+            374
+        );
+        assert_eq!(
+            universe(Expansion::uniform(10)).unwrap().sum_of_distances(),
+            1030
+        );
+        assert_eq!(
+            universe(Expansion::uniform(100))
+                .unwrap()
+                .sum_of_distances(),
+            8410
+        );
+    }
+
+    #[test]
+    fn independent_axis_factors() {
+        let universe = universe(Expansion::new(
+            AxisExpansion::Uniform(1),
+            AxisExpansion::Uniform(3),
+        ))
+        .unwrap();
+
+        // Galaxy 2 sits right of the empty columns 2 and 5
+        assert_eq!(universe.galaxy(2), Some(Point::new(11, 1)));
+        assert_eq!(universe.galaxy(9), Some(Point::new(6, 9)));
+        assert_eq!(universe.galaxy(0), None);
+        assert_eq!(universe.galaxy(10), None);
+    }
+
+    #[test]
+    fn per_line_factors() {
+        let universe = universe(Expansion::new(
+            AxisExpansion::PerLine(vec![2, 5]),
+            AxisExpansion::PerLine(vec![1, 1, 10]),
+        ))
+        .unwrap();
+
+        assert_eq!(universe.galaxy(1), Some(Point::new(3, 0)));
+        assert_eq!(universe.galaxy(4), Some(Point::new(6, 5)));
+        assert_eq!(universe.galaxy(6), Some(Point::new(18, 7)));
+        assert_eq!(universe.galaxy(8), Some(Point::new(0, 14)));
+        assert_eq!(
+            universe.expand_point(&Point::new(9, 9)),
+            Some(Point::new(18, 14))
+        );
+        assert_eq!(universe.expand_point(&Point::new(10, 0)), None);
+    }
+
+    #[test]
+    fn rejects_mismatched_factor_lists() {
+        assert_eq!(
+            universe(Expansion::new(
+                AxisExpansion::PerLine(vec![2]),
+                AxisExpansion::Uniform(2),
+            ))
+            .unwrap_err(),
+            "Got 1 expansion factors for 2 empty lines"
+        );
+    }
+
+    #[test]
+    fn rejects_shrinking_factors() {
+        assert!(universe(Expansion::uniform(0)).is_err());
+    }
+}
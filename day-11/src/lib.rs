@@ -0,0 +1,105 @@
+pub mod distances;
+pub mod expansion;
+pub mod map;
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone)]
+pub struct Point {
+    pub x: i128,
+    pub y: i128,
+}
+
+impl Point {
+    pub fn new(x: i128, y: i128) -> Self {
+        Point { x, y }
+    }
+}
+
+/// Sum of the Manhattan distances between every unordered pair of galaxies.
+///
+/// The x and y parts of the distance are independent, so each axis is summed on its own
+/// from sorted coordinates: the `i`-th smallest value is `i` times larger than everything
+/// before it, minus their running total.
+pub fn sum_of_distances(galaxies: &[Point]) -> i128 {
+    axis_sum(galaxies.iter().map(|g| g.x).collect())
+        + axis_sum(galaxies.iter().map(|g| g.y).collect())
+}
+
+fn axis_sum(mut values: Vec<i128>) -> i128 {
+    values.sort_unstable();
+
+    let mut prefix = 0;
+    let mut sum = 0;
+
+    for (i, value) in values.iter().enumerate() {
+        sum += value * i as i128 - prefix;
+        prefix += value;
+    }
+
+    sum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    pub(crate) const INPUT: &str = "...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+
+    fn brute_force(galaxies: &[Point]) -> i128 {
+        let mut sum = 0;
+
+        for (i, a) in galaxies.iter().enumerate() {
+            for b in &galaxies[i + 1..] {
+                sum += (a.x - b.x).abs() + (a.y - b.y).abs();
+            }
+        }
+
+        sum
+    }
+
+    #[test]
+    fn matches_pairwise_sum() {
+        let galaxies = [
+            Point::new(4, 0),
+            Point::new(9, 1),
+            Point::new(0, 2),
+            Point::new(8, 5),
+            Point::new(1, 6),
+            Point::new(12, 7),
+            Point::new(9, 10),
+            Point::new(0, 11),
+            Point::new(5, 11),
+        ];
+        assert_eq!(sum_of_distances(&galaxies), 374);
+        assert_eq!(sum_of_distances(&galaxies), brute_force(&galaxies));
+    }
+
+    #[test]
+    fn handles_duplicates_and_single_galaxies() {
+        assert_eq!(sum_of_distances(&[]), 0);
+        assert_eq!(sum_of_distances(&[Point::new(3, 3)]), 0);
+        assert_eq!(
+            sum_of_distances(&[Point::new(1, 1), Point::new(1, 1), Point::new(4, 5)]),
+            14
+        );
+    }
+
+    #[test]
+    fn handles_many_galaxies() {
+        let galaxies = (0..300_000)
+            .map(|i| Point::new((i * 7919) % 100_003, (i * 104_729) % 65_537))
+            .collect::<Vec<_>>();
+        let sample = &galaxies[..500];
+
+        assert_eq!(sum_of_distances(sample), brute_force(sample));
+        assert!(sum_of_distances(&galaxies) > 0);
+    }
+}
@@ -0,0 +1,205 @@
+use crate::Point;
+use std::fmt;
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Copy, Clone)]
+pub enum Tile {
+    Empty,
+    Galaxy,
+}
+
+/// A rectangular image of the universe, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GalaxyMap {
+    tiles: Vec<Tile>,
+    pub rows: usize,
+    pub columns: usize,
+}
+
+impl GalaxyMap {
+    pub fn parse(input: &str) -> Result<GalaxyMap, String> {
+        let lines = input.lines().map(|line| line.trim()).collect::<Vec<_>>();
+
+        let columns = match lines.first() {
+            Some(line) => line.chars().count(),
+            None => return Err("Empty galaxy map".to_owned()),
+        };
+
+        let mut tiles = Vec::with_capacity(lines.len() * columns);
+
+        for (i, line) in lines.iter().enumerate() {
+            let width = line.chars().count();
+
+            if width != columns {
+                return Err(format!(
+                    "Row {} has {} tiles, expected {}",
+                    i + 1,
+                    width,
+                    columns
+                ));
+            }
+
+            tiles.extend(line.chars().map(|c| match c {
+                '#' => Tile::Galaxy,
+                _ => Tile::Empty,
+            }));
+        }
+
+        Ok(GalaxyMap {
+            tiles,
+            rows: lines.len(),
+            columns,
+        })
+    }
+
+    pub fn row(&self, idx: usize) -> Result<Vec<Tile>, String> {
+        if idx >= self.rows {
+            return Err("Out of bounds".to_owned());
+        }
+
+        let start = idx * self.columns;
+
+        Ok(self.tiles[start..(start + self.columns)].to_vec())
+    }
+
+    pub fn column(&self, idx: usize) -> Result<Vec<Tile>, String> {
+        if idx >= self.columns {
+            return Err("Out of bounds".to_owned());
+        }
+
+        Ok((0..self.rows)
+            .map(|i| self.tiles[idx + i * self.columns])
+            .collect())
+    }
+
+    pub fn empty_rows(&self) -> Vec<usize> {
+        (0..self.rows)
+            .filter(|i| self.row(*i).unwrap().iter().all(|t| *t == Tile::Empty))
+            .collect()
+    }
+
+    pub fn empty_columns(&self) -> Vec<usize> {
+        (0..self.columns)
+            .filter(|i| self.column(*i).unwrap().iter().all(|t| *t == Tile::Empty))
+            .collect()
+    }
+
+    /// Galaxy positions in reading order, before any expansion.
+    pub fn galaxies(&self) -> Vec<Point> {
+        self.tiles
+            .iter()
+            .enumerate()
+            .filter(|(_, t)| **t == Tile::Galaxy)
+            .map(|(i, _)| {
+                let x = i % self.columns;
+                let y = i / self.columns;
+
+                Point::new(x as i128, y as i128)
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for GalaxyMap {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for i in 0..self.rows {
+            for tile in self.row(i).unwrap() {
+                let c = match tile {
+                    Tile::Empty => '.',
+                    Tile::Galaxy => '#',
+                };
+                write!(f, "{}", c)?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expansion::{Expansion, Universe};
+
+    fn expanded_galaxies(map: &GalaxyMap, factor: i128) -> Vec<Point> {
+        Universe::new(map, &Expansion::uniform(factor))
+            .unwrap()
+            .galaxies
+    }
+
+    #[test]
+    fn wide_map() {
+        let map = GalaxyMap::parse(
+            "#.....#...
+..........
+...#......",
+        )
+        .unwrap();
+
+        assert_eq!((map.rows, map.columns), (3, 10));
+        assert_eq!(map.empty_rows(), vec![1]);
+        assert_eq!(map.empty_columns(), vec![1, 2, 4, 5, 7, 8, 9]);
+        assert_eq!(
+            map.column(9).unwrap(),
+            vec![Tile::Empty, Tile::Empty, Tile::Empty]
+        );
+        assert!(map.column(10).is_err());
+        assert_eq!(
+            expanded_galaxies(&map, 2),
+            vec![Point::new(0, 0), Point::new(10, 0), Point::new(5, 3)]
+        );
+    }
+
+    #[test]
+    fn tall_map() {
+        let map = GalaxyMap::parse(
+            "#..
+...
+...
+..#
+...
+.#.",
+        )
+        .unwrap();
+
+        assert_eq!((map.rows, map.columns), (6, 3));
+        assert_eq!(map.empty_rows(), vec![1, 2, 4]);
+        assert_eq!(map.empty_columns(), Vec::<usize>::new());
+        assert_eq!(
+            map.row(5).unwrap(),
+            vec![Tile::Empty, Tile::Galaxy, Tile::Empty]
+        );
+        assert!(map.row(6).is_err());
+        assert_eq!(
+            expanded_galaxies(&map, 2),
+            vec![Point::new(0, 0), Point::new(2, 5), Point::new(1, 8)]
+        );
+    }
+
+    #[test]
+    fn rejects_ragged_rows() {
+        assert_eq!(
+            GalaxyMap::parse(
+                "#...
+..
+...#"
+            )
+            .unwrap_err(),
+            "Row 2 has 2 tiles, expected 4"
+        );
+    }
+
+    #[test]
+    fn rejects_empty_map() {
+        assert!(GalaxyMap::parse("").is_err());
+    }
+
+    #[test]
+    fn renders_map() {
+        let input = "#..
+...
+.#.
+";
+        assert_eq!(GalaxyMap::parse(input).unwrap().to_string(), input);
+    }
+}
@@ -0,0 +1,285 @@
+use crate::counter::Counter;
+use crate::record::{Record, Spring};
+
+/// Small SplitMix64 generator so samples can be reproduced from a seed.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform value in `0..bound`, rejecting draws that would bias the low end.
+    pub fn below(&mut self, bound: u128) -> u128 {
+        let zone = u128::MAX - u128::MAX % bound;
+
+        loop {
+            let value = (self.next_u64() as u128) << 64 | self.next_u64() as u128;
+
+            if value < zone {
+                return value % bound;
+            }
+        }
+    }
+}
+
+/// The concrete arrangements of one record, ranked in lexicographic order of their
+/// rendered form, where `#` sorts before `.`.
+#[derive(Debug)]
+pub struct Arrangements {
+    record: Record,
+    counter: Counter<u128>,
+    total: u128,
+}
+
+impl Arrangements {
+    pub fn new(record: Record) -> Result<Self, String> {
+        let mut counter = Counter::default();
+        let total = counter
+            .count(&record.springs, &record.groups)
+            .ok_or("Too many arrangements to rank")?;
+
+        Ok(Arrangements {
+            record,
+            counter,
+            total,
+        })
+    }
+
+    pub fn len(&self) -> u128 {
+        self.total
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.total == 0
+    }
+
+    /// The arrangement at `rank`, counting from 0.
+    ///
+    /// Walks the springs once, choosing at each position between starting the next group
+    /// and leaving the spring operational by comparing `rank` with the number of
+    /// arrangements that start with the group.
+    pub fn nth(&self, rank: u128) -> Option<Vec<Spring>> {
+        if rank >= self.total {
+            return None;
+        }
+
+        let springs = &self.record.springs;
+        let groups = &self.record.groups;
+        let n = springs.len();
+        let mut output = Vec::with_capacity(n);
+        let mut rank = rank;
+        let mut i = 0;
+        let mut j = 0;
+
+        while i < n {
+            if let Some(&group) = groups.get(j) {
+                if self.counter.fits(springs, i, group) {
+                    let next = (i + group + 1).min(n);
+                    let with_group = *self
+                        .counter
+                        .ways(next, j + 1)
+                        .expect("states below a counted total fit");
+
+                    if rank < with_group {
+                        output.extend(std::iter::repeat_n(Spring::Damaged, group));
+                        output.extend(std::iter::repeat_n(Spring::Operational, next - i - group));
+                        i = next;
+                        j += 1;
+                        continue;
+                    }
+
+                    rank -= with_group;
+                }
+            }
+
+            output.push(Spring::Operational);
+            i += 1;
+        }
+
+        Some(output)
+    }
+
+    /// Every arrangement in order, each built only when it is reached.
+    pub fn iter(&self) -> impl Iterator<Item = Vec<Spring>> + '_ {
+        (0..self.total).filter_map(|rank| self.nth(rank))
+    }
+
+    /// An arrangement picked uniformly at random.
+    pub fn sample(&self, rng: &mut Rng) -> Option<Vec<Spring>> {
+        if self.is_empty() {
+            return None;
+        }
+
+        self.nth(rng.below(self.total))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::record::{render, Unfold};
+
+    fn arrangements(line: &str) -> Arrangements {
+        Arrangements::new(Record::parse(line).unwrap()).unwrap()
+    }
+
+    /// Every assignment of the unknown springs that matches the groups, sorted.
+    fn brute_force(line: &str) -> Vec<String> {
+        let record = Record::parse(line).unwrap();
+        let unknown = record
+            .springs
+            .iter()
+            .filter(|s| **s == Spring::Unknown)
+            .count();
+        let mut output = vec![];
+
+        for mask in 0..(1 << unknown) {
+            let mut bit = 0;
+            let candidate = record
+                .springs
+                .iter()
+                .map(|s| match s {
+                    Spring::Unknown => {
+                        bit += 1;
+                        if mask & (1 << (bit - 1)) != 0 {
+                            '#'
+                        } else {
+                            '.'
+                        }
+                    }
+                    s => s.to_char(),
+                })
+                .collect::<String>();
+            let groups = candidate
+                .split('.')
+                .map(|s| s.len())
+                .filter(|n| *n > 0)
+                .collect::<Vec<_>>();
+
+            if groups == record.groups {
+                output.push(candidate);
+            }
+        }
+
+        output.sort();
+        output
+    }
+
+    #[test]
+    fn enumerates_in_lexicographic_order() {
+        let all = arrangements(".??..??...?##. 1,1,3")
+            .iter()
+            .map(|a| render(&a))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            all,
+            vec![
+                ".#...#....###.",
+                ".#....#...###.",
+                "..#..#....###.",
+                "..#...#...###.",
+            ]
+        );
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        for line in [
+            "???.### 1,1,3",
+            "?#?#?#?#?#?#?#? 1,3,1,6",
+            "????.######..#####. 1,6,5",
+            "?###???????? 3,2,1",
+            "??????? 2,1",
+            "#.# 1,2",
+        ] {
+            let found = arrangements(line)
+                .iter()
+                .map(|a| render(&a))
+                .collect::<Vec<_>>();
+
+            assert_eq!(found, brute_force(line), "{}", line);
+        }
+    }
+
+    #[test]
+    fn ranks_into_large_unfolded_records() {
+        let record = Record::parse_line("?###???????? 3,2,1", &Unfold::puzzle()).unwrap();
+        let groups = record.groups.clone();
+        let arrangements = Arrangements::new(record).unwrap();
+
+        assert_eq!(arrangements.len(), 506250);
+        assert_eq!(arrangements.nth(506250), None);
+
+        let ranks = [0, 1, 2, 1000, 250000, 506248, 506249];
+        let found = ranks
+            .iter()
+            .map(|rank| render(&arrangements.nth(*rank).unwrap()))
+            .collect::<Vec<_>>();
+
+        assert!(found.windows(2).all(|w| w[0] < w[1]));
+
+        for arrangement in found {
+            let found_groups = arrangement
+                .split('.')
+                .map(|s| s.len())
+                .filter(|n| *n > 0)
+                .collect::<Vec<_>>();
+
+            assert_eq!(found_groups, groups);
+        }
+    }
+
+    #[test]
+    fn samples_are_valid_and_reproducible() {
+        let arrangements = arrangements("?###???????? 3,2,1");
+        let valid = brute_force("?###???????? 3,2,1");
+
+        let mut rng = Rng::new(7);
+        let first = (0..50)
+            .map(|_| render(&arrangements.sample(&mut rng).unwrap()))
+            .collect::<Vec<_>>();
+
+        let mut rng = Rng::new(7);
+        let second = (0..50)
+            .map(|_| render(&arrangements.sample(&mut rng).unwrap()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(first, second);
+        assert!(first.iter().all(|a| valid.contains(a)));
+        assert_eq!(
+            Arrangements::new(Record::parse("#.# 2").unwrap())
+                .unwrap()
+                .sample(&mut rng),
+            None
+        );
+    }
+
+    #[test]
+    fn samples_cover_every_arrangement() {
+        let arrangements = arrangements("?###???????? 3,2,1");
+        let mut rng = Rng::new(1);
+        let mut seen = vec![0; arrangements.len() as usize];
+
+        for _ in 0..2000 {
+            let sample = arrangements.sample(&mut rng).unwrap();
+            let rank = arrangements.iter().position(|a| a == sample).unwrap();
+            seen[rank] += 1;
+        }
+
+        assert!(seen.iter().all(|n| *n > 100));
+    }
+}
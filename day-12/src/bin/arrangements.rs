@@ -0,0 +1,73 @@
+use day_12::{render, Arrangements, Record, Rng, Unfold};
+
+// Usage: arrangements "<record>" [all | rank <k> | sample <count> [seed]]
+fn process(args: &[String]) -> Result<Vec<String>, String> {
+    let line = args.first().ok_or("No record given")?;
+    let arrangements = Arrangements::new(Record::parse_line(line, &Unfold::none())?)?;
+    let number = |i: usize| -> Result<u64, String> {
+        args.get(i)
+            .ok_or_else(|| format!("Missing argument {}", i + 1))?
+            .parse::<u64>()
+            .map_err(|err| err.to_string())
+    };
+
+    let output = match args.get(1).map(|s| s.as_str()) {
+        None | Some("all") => arrangements.iter().map(|a| render(&a)).collect(),
+        Some("rank") => {
+            let rank = number(2)? as u128;
+            let arrangement = arrangements
+                .nth(rank)
+                .ok_or_else(|| format!("Only {} arrangements", arrangements.len()))?;
+
+            vec![render(&arrangement)]
+        }
+        Some("sample") => {
+            let count = number(2)?;
+            let mut rng = Rng::new(number(3).unwrap_or(0));
+
+            (0..count)
+                .filter_map(|_| arrangements.sample(&mut rng))
+                .map(|a| render(&a))
+                .collect()
+        }
+        Some(mode) => return Err(format!("Unknown mode: {}", mode)),
+    };
+
+    Ok(output)
+}
+
+fn main() {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    match process(&args) {
+        Ok(arrangements) => {
+            for arrangement in arrangements {
+                println!("{}", arrangement);
+            }
+        }
+        Err(err) => println!("Error: {}", err),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn base_case() {
+        assert_eq!(process(&args(&["???.### 1,1,3"])).unwrap(), vec!["#.#.###"]);
+        assert_eq!(
+            process(&args(&[".??..??...?##. 1,1,3", "rank", "3"])).unwrap(),
+            vec!["..#...#...###."]
+        );
+        assert_eq!(
+            process(&args(&[".??..??...?##. 1,1,3", "sample", "5", "42"]))
+                .unwrap()
+                .len(),
+            5
+        );
+    }
+}
@@ -0,0 +1,267 @@
+use crate::record::{Record, Spring, Unfold};
+use num_bigint::BigUint;
+
+/// A number type arrangements can be counted in.
+pub trait Count: Clone {
+    fn zero() -> Self;
+    fn one() -> Self;
+    /// `None` when the sum does not fit in the type.
+    fn checked_add(&self, other: &Self) -> Option<Self>;
+}
+
+macro_rules! impl_count {
+    ($($t:ty),*) => {
+        $(
+            impl Count for $t {
+                fn zero() -> Self {
+                    0
+                }
+
+                fn one() -> Self {
+                    1
+                }
+
+                fn checked_add(&self, other: &Self) -> Option<Self> {
+                    <$t>::checked_add(*self, *other)
+                }
+            }
+        )*
+    };
+}
+
+impl_count!(u32, u64, u128, usize);
+
+impl Count for BigUint {
+    fn zero() -> Self {
+        BigUint::ZERO
+    }
+
+    fn one() -> Self {
+        BigUint::from(1_u32)
+    }
+
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(self + other)
+    }
+}
+
+/// Counts arrangements with a table of `ways[i][j]`: the number of ways the springs from
+/// position `i` onwards can hold the groups from index `j` onwards, or `None` if that does
+/// not fit in `C`. The buffers are kept between records so each line only pays for resizing
+/// them.
+#[derive(Debug)]
+pub struct Counter<C: Count = u128> {
+    ways: Vec<Option<C>>,
+    width: usize,
+    /// Length of the run of non-operational springs starting at each position.
+    runs: Vec<usize>,
+}
+
+impl<C: Count> Default for Counter<C> {
+    fn default() -> Self {
+        Counter {
+            ways: vec![],
+            width: 0,
+            runs: vec![],
+        }
+    }
+}
+
+impl<C: Count> Counter<C> {
+    /// Number of arrangements, or `None` if it does not fit in `C`.
+    pub fn count(&mut self, springs: &[Spring], groups: &[usize]) -> Option<C> {
+        let n = springs.len();
+        let m = groups.len();
+        let width = m + 1;
+        self.width = width;
+
+        self.runs.clear();
+        self.runs.resize(n + 1, 0);
+
+        for i in (0..n).rev() {
+            if springs[i] != Spring::Operational {
+                self.runs[i] = self.runs[i + 1] + 1;
+            }
+        }
+
+        self.ways.clear();
+        self.ways.resize((n + 1) * width, Some(C::zero()));
+        self.ways[n * width + m] = Some(C::one());
+
+        for i in (0..n).rev() {
+            for j in 0..=m {
+                let mut ways = Some(C::zero());
+
+                // Leave this spring operational
+                if springs[i] != Spring::Damaged {
+                    ways = self.ways[(i + 1) * width + j].clone();
+                }
+
+                // Start the next group here, followed by an operational spring or the end
+                if let Some(&group) = groups.get(j) {
+                    if self.fits(springs, i, group) {
+                        let next = &self.ways[(i + group + 1).min(n) * width + j + 1];
+
+                        // An overflow only matters if it feeds into the total
+                        ways = match (ways, next) {
+                            (Some(ways), Some(next)) => ways.checked_add(next),
+                            _ => None,
+                        };
+                    }
+                }
+
+                self.ways[i * width + j] = ways;
+            }
+        }
+
+        self.ways[0].clone()
+    }
+
+    /// Ways for springs `i..` and groups `j..` from the last call to [`Counter::count`].
+    ///
+    /// Only `None` for states that overflowed, which never feed into a total that fits.
+    pub(crate) fn ways(&self, i: usize, j: usize) -> Option<&C> {
+        self.ways[i * self.width + j].as_ref()
+    }
+
+    /// Whether a group of `group` damaged springs can start at position `i`.
+    pub(crate) fn fits(&self, springs: &[Spring], i: usize, group: usize) -> bool {
+        let end = i + group;
+
+        self.runs[i] >= group && (end == springs.len() || springs[end] != Spring::Damaged)
+    }
+}
+
+/// Sums the arrangements of every line, reporting the first line that overflows `C`.
+pub fn count_lines<C: Count>(input: &str, unfold: &Unfold) -> Result<C, String> {
+    let mut counter = Counter::<C>::default();
+    let mut sum = C::zero();
+
+    for (i, line) in input.lines().enumerate() {
+        let record =
+            Record::parse_line(line, unfold).map_err(|err| format!("{} on line {}", err, i + 1))?;
+        let count = counter
+            .count(&record.springs, &record.groups)
+            .ok_or_else(|| format!("Arrangement count overflows on line {}", i + 1))?;
+
+        sum = sum
+            .checked_add(&count)
+            .ok_or_else(|| format!("Sum of arrangement counts overflows on line {}", i + 1))?;
+    }
+
+    Ok(sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arrangements::Arrangements;
+
+    #[test]
+    fn counts_each_example_row() {
+        let mut counter = Counter::<usize>::default();
+        let rows = [
+            ("???.### 1,1,3", 1),
+            (".??..??...?##. 1,1,3", 4),
+            ("?#?#?#?#?#?#?#? 1,3,1,6", 1),
+            ("????.#...#... 4,1,1", 1),
+            ("????.######..#####. 1,6,5", 4),
+            ("?###???????? 3,2,1", 10),
+        ];
+
+        for (line, expected) in rows {
+            let record = Record::parse(line).unwrap();
+            assert_eq!(
+                counter.count(&record.springs, &record.groups),
+                Some(expected)
+            );
+        }
+    }
+
+    #[test]
+    fn long_rows() {
+        let mut counter = Counter::<usize>::default();
+        let row = format!("{}???", "#.".repeat(1000));
+        let springs = row
+            .chars()
+            .map(|c| Spring::try_from(c).unwrap())
+            .collect::<Vec<_>>();
+        let groups = vec![1; 1001];
+
+        assert_eq!(counter.count(&springs, &groups), Some(3));
+        assert_eq!(counter.count(&springs[..4], &groups[..2]), Some(1));
+    }
+
+    fn binomial(n: u32, k: u32) -> BigUint {
+        (0..k).fold(BigUint::from(1_u32), |acc, i| acc * (n - i) / (i + 1))
+    }
+
+    #[test]
+    fn reports_overflowing_line() {
+        let input = "???.### 1,1,3
+?????????? 1
+?###???????? 3,2,1";
+        let unfold = Unfold::new(40, vec![Spring::Unknown], vec![]);
+
+        assert_eq!(
+            count_lines::<u128>(input, &Unfold::puzzle()),
+            Ok(1 + 2118760 + 506250)
+        );
+        assert_eq!(
+            count_lines::<u64>(input, &unfold).unwrap_err(),
+            "Arrangement count overflows on line 2"
+        );
+        assert_eq!(
+            count_lines::<u128>(input, &unfold).unwrap_err(),
+            "Arrangement count overflows on line 2"
+        );
+    }
+
+    #[test]
+    fn ignores_overflow_in_states_that_add_nothing() {
+        // The groups can never fit after "##", however many ways the unknowns could hold them
+        let line = format!("##{} {}", "?".repeat(200), vec!["1"; 60].join(","));
+
+        assert_eq!(count_lines::<u64>(&line, &Unfold::none()), Ok(0));
+        assert!(Arrangements::new(Record::parse(&line).unwrap())
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn reports_empty_group_with_its_line() {
+        assert_eq!(
+            count_lines::<u64>("#. 1\n##.? 2,0", &Unfold::none()).unwrap_err(),
+            "Invalid group size: 0 at group 2 on line 2"
+        );
+    }
+
+    #[test]
+    fn reports_overflowing_sum() {
+        // Each line has C(35, 15) = 3247943160 arrangements, which fits in a u32 on its own
+        let line = format!("{} {}", "?".repeat(49), vec!["1"; 15].join(","));
+        let input = format!("{}\n{}", line, line);
+
+        assert_eq!(count_lines::<u32>(&line, &Unfold::none()), Ok(3247943160));
+        assert_eq!(
+            count_lines::<u32>(&input, &Unfold::none()).unwrap_err(),
+            "Sum of arrangement counts overflows on line 2"
+        );
+    }
+
+    #[test]
+    fn counts_exactly_with_big_integers() {
+        let input = "?????????? 1,1,1,1";
+        let unfold = Unfold::new(10, vec![Spring::Unknown], vec![]);
+
+        // 109 unknown springs holding 40 single damaged springs
+        assert_eq!(count_lines::<BigUint>(input, &unfold), Ok(binomial(70, 40)));
+        assert_eq!(
+            count_lines::<BigUint>(
+                "?????????? 1",
+                &Unfold::new(40, vec![Spring::Unknown], vec![])
+            ),
+            Ok(binomial(400, 40))
+        );
+    }
+}
@@ -0,0 +1,7 @@
+pub mod arrangements;
+pub mod counter;
+pub mod record;
+
+pub use arrangements::{Arrangements, Rng};
+pub use counter::{count_lines, Count, Counter};
+pub use record::{render, Record, Spring, Unfold};
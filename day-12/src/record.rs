@@ -0,0 +1,182 @@
+#[derive(Debug, Hash, Clone, Copy, PartialEq, Eq)]
+pub enum Spring {
+    Operational,
+    Damaged,
+    Unknown,
+}
+
+impl TryFrom<char> for Spring {
+    type Error = String;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '.' => Ok(Spring::Operational),
+            '#' => Ok(Spring::Damaged),
+            '?' => Ok(Spring::Unknown),
+            _ => Err(format!("Invalid spring: {}", c)),
+        }
+    }
+}
+
+impl Spring {
+    pub fn to_char(self) -> char {
+        match self {
+            Spring::Operational => '.',
+            Spring::Damaged => '#',
+            Spring::Unknown => '?',
+        }
+    }
+}
+
+pub fn render(springs: &[Spring]) -> String {
+    springs.iter().map(|s| s.to_char()).collect()
+}
+
+/// How a folded record is copied out to its full size.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Unfold {
+    /// Number of copies of the record, at least 1.
+    pub factor: usize,
+    /// Springs placed between consecutive copies of the springs.
+    pub spring_separator: Vec<Spring>,
+    /// Groups placed between consecutive copies of the groups.
+    pub group_separator: Vec<usize>,
+}
+
+impl Unfold {
+    /// Leaves the record as written, for part 1.
+    pub fn none() -> Self {
+        Unfold::new(1, vec![], vec![])
+    }
+
+    /// Five copies joined by an unknown spring, for part 2.
+    pub fn puzzle() -> Self {
+        Unfold::new(5, vec![Spring::Unknown], vec![])
+    }
+
+    pub fn new(factor: usize, spring_separator: Vec<Spring>, group_separator: Vec<usize>) -> Self {
+        Unfold {
+            factor,
+            spring_separator,
+            group_separator,
+        }
+    }
+}
+
+fn repeat_with<T: Clone>(items: &[T], separator: &[T], factor: usize) -> Vec<T> {
+    let mut output = Vec::with_capacity(items.len() * factor + separator.len() * factor);
+
+    for i in 0..factor {
+        if i > 0 {
+            output.extend_from_slice(separator);
+        }
+        output.extend_from_slice(items);
+    }
+
+    output
+}
+
+#[derive(Debug, Hash, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub springs: Vec<Spring>,
+    pub groups: Vec<usize>,
+}
+
+impl Record {
+    pub fn new(springs: Vec<Spring>, groups: Vec<usize>) -> Self {
+        Record { springs, groups }
+    }
+
+    /// Parses a folded line such as `???.### 1,1,3`.
+    pub fn parse(line: &str) -> Result<Self, String> {
+        let mut result = line.split_whitespace();
+
+        let springs = result
+            .next()
+            .ok_or("No springs")?
+            .chars()
+            .map(Spring::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let groups = result
+            .next()
+            .ok_or("No groups")?
+            .split(',')
+            .enumerate()
+            .map(|(i, s)| match s.parse::<usize>() {
+                Ok(size) if size > 0 => Ok(size),
+                _ => Err(format!("Invalid group size: {} at group {}", s, i + 1)),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Record::new(springs, groups))
+    }
+
+    pub fn parse_line(line: &str, unfold: &Unfold) -> Result<Self, String> {
+        Record::parse(line)?.unfold(unfold)
+    }
+
+    pub fn unfold(&self, unfold: &Unfold) -> Result<Self, String> {
+        if unfold.factor == 0 {
+            return Err("Unfold factor must be at least 1".to_owned());
+        }
+        if unfold.group_separator.contains(&0) {
+            return Err("Separator group sizes must be at least 1".to_owned());
+        }
+
+        Ok(Record::new(
+            repeat_with(&self.springs, &unfold.spring_separator, unfold.factor),
+            repeat_with(&self.groups, &unfold.group_separator, unfold.factor),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn springs(s: &str) -> Vec<Spring> {
+        s.chars().map(|c| Spring::try_from(c).unwrap()).collect()
+    }
+
+    #[test]
+    fn unfolds_like_the_puzzle() {
+        let record = Record::parse_line(".# 1", &Unfold::puzzle()).unwrap();
+
+        assert_eq!(record.springs, springs(".#?.#?.#?.#?.#"));
+        assert_eq!(record.groups, vec![1, 1, 1, 1, 1]);
+    }
+
+    #[test]
+    fn keeps_record_without_unfolding() {
+        let record = Record::parse_line("???.### 1,1,3", &Unfold::none()).unwrap();
+
+        assert_eq!(record.springs, springs("???.###"));
+        assert_eq!(record.groups, vec![1, 1, 3]);
+    }
+
+    #[test]
+    fn unfolds_with_custom_separators() {
+        let unfold = Unfold::new(3, springs(".."), vec![2]);
+        let record = Record::parse_line("#? 1", &unfold).unwrap();
+
+        assert_eq!(record.springs, springs("#?..#?..#?"));
+        assert_eq!(record.groups, vec![1, 2, 1, 2, 1]);
+    }
+
+    #[test]
+    fn rejects_bad_input() {
+        assert_eq!(Record::parse("#x# 1").unwrap_err(), "Invalid spring: x");
+        assert_eq!(
+            Record::parse("### 1,a").unwrap_err(),
+            "Invalid group size: a at group 2"
+        );
+        assert_eq!(
+            Record::parse("### 3,0").unwrap_err(),
+            "Invalid group size: 0 at group 2"
+        );
+        assert_eq!(Record::parse("###").unwrap_err(), "No groups");
+        assert!(Record::parse_line("### 3", &Unfold::new(0, vec![], vec![])).is_err());
+        assert!(Record::parse_line("### 3", &Unfold::new(2, vec![], vec![0])).is_err());
+    }
+}
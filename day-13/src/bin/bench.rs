@@ -0,0 +1,90 @@
+use day_13::chars::CharPattern;
+use day_13::Pattern;
+use std::time::Instant;
+
+/// Random pattern with a vertical mirror through the middle, from a xorshift seed.
+/// With `smudged`, the top-left cell is flipped so the mirror needs one repair.
+fn generate(width: usize, height: usize, smudged: bool, seed: &mut u64) -> String {
+    let mut output = String::new();
+
+    for _ in 0..height {
+        let half = (0..width / 2)
+            .map(|_| {
+                *seed ^= *seed << 13;
+                *seed ^= *seed >> 7;
+                *seed ^= *seed << 17;
+                if seed.is_multiple_of(2) {
+                    '#'
+                } else {
+                    '.'
+                }
+            })
+            .collect::<String>();
+
+        output.push_str(&half);
+        output.extend(half.chars().rev());
+        output.push('\n');
+    }
+
+    if smudged {
+        let first = if output.starts_with('#') { "." } else { "#" };
+        output.replace_range(0..1, first);
+    }
+
+    output
+}
+
+fn main() {
+    let args = std::env::args().collect::<Vec<_>>();
+    let size = args.get(1).and_then(|s| s.parse().ok()).unwrap_or(500);
+    let count = args.get(2).and_then(|s| s.parse().ok()).unwrap_or(20);
+
+    let mut seed = 0x2023_1213;
+    let notes = (0..count)
+        .map(|i| generate(size, size, i % 2 == 1, &mut seed))
+        .collect::<Vec<_>>();
+
+    println!("{} patterns of {}x{}", count, size, size);
+
+    let now = Instant::now();
+    let char_patterns = notes
+        .iter()
+        .map(|note| CharPattern::from(note.as_str()))
+        .collect::<Vec<_>>();
+    println!("chars, parse: {:?}", now.elapsed());
+
+    let now = Instant::now();
+    let mask_patterns = notes
+        .iter()
+        .map(|note| Pattern::parse(note).unwrap())
+        .collect::<Vec<_>>();
+    println!("masks, parse: {:?}", now.elapsed());
+
+    for smudges in 0..2 {
+        let now = Instant::now();
+        let chars = char_patterns
+            .iter()
+            .map(|pattern| pattern.score(smudges))
+            .sum::<usize>();
+        println!(
+            "chars, {} smudges: {} ({:?})",
+            smudges,
+            chars,
+            now.elapsed()
+        );
+
+        let now = Instant::now();
+        let masks = mask_patterns
+            .iter()
+            .map(|pattern| pattern.score(smudges))
+            .sum::<usize>();
+        println!(
+            "masks, {} smudges: {} ({:?})",
+            smudges,
+            masks,
+            now.elapsed()
+        );
+
+        assert_eq!(chars, masks);
+    }
+}
@@ -0,0 +1,40 @@
+use day_13::parse_notes;
+
+fn main() {
+    let smudges = std::env::args()
+        .nth(1)
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(1);
+    let input = include_str!("./input.txt");
+
+    let notes = match parse_notes(input) {
+        Ok(notes) => notes,
+        Err(err) => return println!("Error: {}", err),
+    };
+
+    for (i, pattern) in notes.iter().enumerate() {
+        let repairs = pattern.repairs(smudges);
+
+        match repairs.len() {
+            0 => println!("Pattern {}: no valid repair", i + 1),
+            1 => println!("Pattern {}:", i + 1),
+            n => println!("Pattern {}: {} valid repairs", i + 1, n),
+        }
+
+        for repair in &repairs {
+            let cells = repair
+                .smudges
+                .iter()
+                .map(|smudge| format!("{:?} or {:?}", smudge.cell, smudge.mirror))
+                .collect::<Vec<_>>();
+
+            println!(
+                "{:?} mirror at {}, smudges: [{}]",
+                repair.reflection.axis,
+                repair.reflection.index,
+                cells.join(", ")
+            );
+            println!("{}", pattern.render_repair(repair));
+        }
+    }
+}
@@ -0,0 +1,46 @@
+//! The original character-by-character comparison, kept as a reference for benchmarks.
+
+use crate::mirrors;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CharPattern {
+    rows: Vec<Vec<char>>,
+    columns: Vec<Vec<char>>,
+}
+
+impl From<&str> for CharPattern {
+    fn from(s: &str) -> Self {
+        let rows = s
+            .lines()
+            .map(|line| line.chars().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let width = rows.first().map(|row| row.len()).unwrap_or(0);
+        let columns = (0..width)
+            .map(|x| rows.iter().map(|row| row[x]).collect())
+            .collect();
+
+        Self { rows, columns }
+    }
+}
+
+fn lines_diff(a: &[char], b: &[char]) -> usize {
+    a.iter().zip(b.iter()).filter(|(a, b)| a != b).count()
+}
+
+impl CharPattern {
+    /// Same as `Pattern::score`.
+    pub fn score(&self, smudges: usize) -> usize {
+        let row = mirrors(&self.rows, smudges, |a, b| lines_diff(a, b))
+            .into_iter()
+            .find(|(_, differences)| *differences == smudges);
+        if let Some((index, _)) = row {
+            return index * 100;
+        }
+
+        mirrors(&self.columns, smudges, |a, b| lines_diff(a, b))
+            .into_iter()
+            .find(|(_, differences)| *differences == smudges)
+            .map(|(index, _)| index)
+            .unwrap_or(0)
+    }
+}
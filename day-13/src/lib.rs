@@ -0,0 +1,366 @@
+pub mod chars;
+pub mod mask;
+pub mod repair;
+
+use mask::Mask;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis {
+    /// A mirror between two rows.
+    Horizontal,
+    /// A mirror between two columns.
+    Vertical,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Reflection {
+    pub axis: Axis,
+    /// Number of rows above, or columns left of, the mirror.
+    pub index: usize,
+    /// Cells that differ from their mirrored counterpart, counted once per pair.
+    pub differences: usize,
+}
+
+impl Reflection {
+    pub fn score(&self) -> usize {
+        match self.axis {
+            Axis::Horizontal => self.index * 100,
+            Axis::Vertical => self.index,
+        }
+    }
+}
+
+/// Mirrors between consecutive `lines` with at most `max_differences` differences, as `(index, differences)`.
+pub(crate) fn mirrors<T>(
+    lines: &[T],
+    max_differences: usize,
+    diff: impl Fn(&T, &T) -> usize,
+) -> Vec<(usize, usize)> {
+    let len = lines.len();
+    let mut output = vec![];
+
+    'lines: for i in 1..len {
+        let mut differences = 0;
+
+        // Fold at i and compare each pair of lines out to the nearest edge
+        for d in 0..i.min(len - i) {
+            differences += diff(&lines[i - d - 1], &lines[i + d]);
+
+            if differences > max_differences {
+                continue 'lines;
+            }
+        }
+
+        output.push((i, differences));
+    }
+
+    output
+}
+
+/// A pattern of ash and rocks, stored both as row masks and as column masks.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pattern {
+    rows: Vec<Mask>,
+    columns: Vec<Mask>,
+}
+
+impl Pattern {
+    pub fn parse(input: &str) -> Result<Pattern, String> {
+        let lines = input.lines().map(str::trim_end).enumerate();
+
+        Pattern::from_lines(&lines.map(|(i, line)| (i + 1, line)).collect::<Vec<_>>())
+    }
+
+    /// Builds a pattern from `(line number, line)` pairs, checking the alphabet and that every
+    /// row is as wide as the first.
+    fn from_lines(lines: &[(usize, &str)]) -> Result<Pattern, String> {
+        let width = match lines.first() {
+            Some((_, line)) => line.chars().count(),
+            None => return Err("Empty pattern".to_owned()),
+        };
+
+        let mut rows = vec![Mask::empty(width); lines.len()];
+        let mut columns = vec![Mask::empty(lines.len()); width];
+
+        for (y, (number, line)) in lines.iter().enumerate() {
+            let cells = line.chars().count();
+
+            if cells != width {
+                return Err(format!(
+                    "Line {} has {} cells, expected {}",
+                    number, cells, width
+                ));
+            }
+
+            for (x, c) in line.chars().enumerate() {
+                match c {
+                    '#' => {
+                        rows[y].set(x);
+                        columns[x].set(y);
+                    }
+                    '.' => (),
+                    _ => {
+                        return Err(format!(
+                            "Invalid character: {} at line {}, column {}",
+                            c,
+                            number,
+                            x + 1
+                        ))
+                    }
+                }
+            }
+        }
+
+        Ok(Pattern { rows, columns })
+    }
+
+    pub fn width(&self) -> usize {
+        self.columns.len()
+    }
+
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Whether the cell at column `x` of row `y` is a rock.
+    pub fn get(&self, x: usize, y: usize) -> bool {
+        self.rows
+            .get(y)
+            .is_some_and(|row| x < self.width() && row.get(x))
+    }
+
+    /// The same pattern with rows and columns swapped.
+    pub fn transpose(&self) -> Pattern {
+        Pattern {
+            rows: self.columns.clone(),
+            columns: self.rows.clone(),
+        }
+    }
+
+    /// Every mirror with at most `max_differences` differences, rows first.
+    pub fn candidates(&self, max_differences: usize) -> Vec<Reflection> {
+        let rows = mirrors(&self.rows, max_differences, Mask::diff)
+            .into_iter()
+            .map(|(index, differences)| Reflection {
+                axis: Axis::Horizontal,
+                index,
+                differences,
+            });
+        let columns = mirrors(&self.columns, max_differences, Mask::diff)
+            .into_iter()
+            .map(|(index, differences)| Reflection {
+                axis: Axis::Vertical,
+                index,
+                differences,
+            });
+
+        rows.chain(columns).collect()
+    }
+
+    /// Mirrors that become perfect after fixing exactly `smudges` cells.
+    pub fn find_reflections(&self, smudges: usize) -> Vec<Reflection> {
+        self.candidates(smudges)
+            .into_iter()
+            .filter(|r| r.differences == smudges)
+            .collect()
+    }
+
+    /// The score of the first mirror with exactly `smudges` smudges, or 0 if there is none.
+    pub fn score(&self, smudges: usize) -> usize {
+        self.find_reflections(smudges)
+            .first()
+            .map(|r| r.score())
+            .unwrap_or(0)
+    }
+}
+
+/// Splits the notes into patterns on runs of blank lines, accepting CRLF line endings and
+/// trailing whitespace.
+pub fn parse_notes(input: &str) -> Result<Vec<Pattern>, String> {
+    let mut patterns = vec![];
+    let mut buffer = vec![];
+
+    for (i, line) in input.lines().map(str::trim_end).enumerate() {
+        if !line.is_empty() {
+            buffer.push((i + 1, line));
+            continue;
+        }
+
+        if !buffer.is_empty() {
+            patterns.push(
+                Pattern::from_lines(&buffer)
+                    .map_err(|err| format!("Pattern {}: {}", patterns.len() + 1, err))?,
+            );
+            buffer.clear();
+        }
+    }
+
+    if !buffer.is_empty() {
+        patterns.push(
+            Pattern::from_lines(&buffer)
+                .map_err(|err| format!("Pattern {}: {}", patterns.len() + 1, err))?,
+        );
+    }
+
+    Ok(patterns)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    pub(crate) fn pattern(input: &str) -> Pattern {
+        Pattern::parse(input).unwrap()
+    }
+
+    pub(crate) const FIRST: &str = "#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.";
+
+    pub(crate) const SECOND: &str = "#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#";
+
+    #[test]
+    fn perfect_reflections() {
+        assert_eq!(
+            pattern(FIRST).find_reflections(0),
+            vec![Reflection {
+                axis: Axis::Vertical,
+                index: 5,
+                differences: 0
+            }]
+        );
+        assert_eq!(
+            pattern(SECOND).find_reflections(0),
+            vec![Reflection {
+                axis: Axis::Horizontal,
+                index: 4,
+                differences: 0
+            }]
+        );
+    }
+
+    #[test]
+    fn single_smudge_reflections() {
+        assert_eq!(pattern(FIRST).score(1), 300);
+        assert_eq!(pattern(SECOND).score(1), 100);
+    }
+
+    #[test]
+    fn candidates_include_difference_counts() {
+        let candidates = pattern(FIRST).candidates(1);
+
+        assert!(candidates.contains(&Reflection {
+            axis: Axis::Vertical,
+            index: 5,
+            differences: 0
+        }));
+        assert!(candidates.contains(&Reflection {
+            axis: Axis::Horizontal,
+            index: 3,
+            differences: 1
+        }));
+        assert!(candidates.iter().all(|r| r.differences <= 1));
+    }
+
+    #[test]
+    fn any_number_of_smudges() {
+        let pattern = pattern(
+            "#..#
+....
+.##.",
+        );
+
+        assert_eq!(pattern.find_reflections(0).len(), 1);
+        assert_eq!(pattern.find_reflections(2).len(), 4);
+        assert!(pattern.find_reflections(2).contains(&Reflection {
+            axis: Axis::Horizontal,
+            index: 1,
+            differences: 2
+        }));
+        assert_eq!(pattern.transpose().transpose(), pattern);
+    }
+
+    #[test]
+    fn wide_patterns() {
+        let row = format!("{}{}", "#..".repeat(30), "..#".repeat(30));
+        let pattern = pattern(
+            format!(
+                "{}\n{}",
+                row,
+                row.replace('#', "x").replace('.', "#").replace('x', ".")
+            )
+            .as_str(),
+        );
+
+        assert_eq!((pattern.width(), pattern.height()), (180, 2));
+        assert!(pattern.get(179, 0));
+        assert!(!pattern.get(180, 0));
+        assert!(pattern.find_reflections(0).contains(&Reflection {
+            axis: Axis::Vertical,
+            index: 90,
+            differences: 0
+        }));
+        assert_eq!(
+            pattern.find_reflections(180).first().map(|r| r.score()),
+            Some(100)
+        );
+    }
+
+    #[test]
+    fn masks_agree_with_chars() {
+        let input = include_str!("./bin/input.txt");
+
+        for note in input.split("\n\n") {
+            for smudges in 0..3 {
+                assert_eq!(
+                    Pattern::parse(note).unwrap().score(smudges),
+                    chars::CharPattern::from(note).score(smudges)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn splits_notes_on_blank_line_runs() {
+        let input = format!(
+            "\r\n{}\r\n\r\n  \n\n{}  \n\n",
+            FIRST.replace('\n', "\r\n"),
+            SECOND
+        );
+        let patterns = parse_notes(&input).unwrap();
+
+        assert_eq!(patterns, vec![pattern(FIRST), pattern(SECOND)]);
+        assert_eq!(parse_notes("").unwrap(), vec![]);
+    }
+
+    #[test]
+    fn rejects_ragged_patterns() {
+        assert_eq!(
+            parse_notes("##\n..\n\n#.#\n##\n").unwrap_err(),
+            "Pattern 2: Line 5 has 2 cells, expected 3"
+        );
+        assert_eq!(
+            Pattern::parse("#.\n.").unwrap_err(),
+            "Line 2 has 1 cells, expected 2"
+        );
+        assert!(Pattern::parse("").is_err());
+    }
+
+    #[test]
+    fn rejects_unknown_cells() {
+        assert_eq!(
+            parse_notes("#.\n.#\n\n\n\n#.\n.O").unwrap_err(),
+            "Pattern 2: Invalid character: O at line 7, column 2"
+        );
+    }
+}
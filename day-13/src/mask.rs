@@ -0,0 +1,99 @@
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// One row or column of a pattern, with a set bit for every rock.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Mask {
+    words: Vec<u64>,
+}
+
+impl Mask {
+    /// A mask of `len` cells with no rocks.
+    pub fn empty(len: usize) -> Mask {
+        Mask {
+            words: vec![0; len.div_ceil(WORD_BITS)],
+        }
+    }
+
+    pub fn set(&mut self, i: usize) {
+        self.words[i / WORD_BITS] |= 1 << (i % WORD_BITS);
+    }
+
+    pub fn from_bits(bits: impl IntoIterator<Item = bool>) -> Mask {
+        let mut words = vec![];
+
+        for (i, bit) in bits.into_iter().enumerate() {
+            if i % WORD_BITS == 0 {
+                words.push(0);
+            }
+            if bit {
+                words[i / WORD_BITS] |= 1 << (i % WORD_BITS);
+            }
+        }
+
+        Mask { words }
+    }
+
+    pub fn get(&self, i: usize) -> bool {
+        self.words
+            .get(i / WORD_BITS)
+            .is_some_and(|word| word >> (i % WORD_BITS) & 1 == 1)
+    }
+
+    /// Number of cells that differ between two masks of the same length.
+    pub fn diff(&self, other: &Mask) -> usize {
+        self.words
+            .iter()
+            .zip(other.words.iter())
+            .map(|(a, b)| (a ^ b).count_ones() as usize)
+            .sum()
+    }
+
+    /// Positions of the cells that differ between two masks of the same length.
+    pub fn differences(&self, other: &Mask) -> Vec<usize> {
+        let mut output = vec![];
+
+        for (i, (a, b)) in self.words.iter().zip(other.words.iter()).enumerate() {
+            let mut bits = a ^ b;
+
+            while bits != 0 {
+                output.push(i * WORD_BITS + bits.trailing_zeros() as usize);
+                bits &= bits - 1;
+            }
+        }
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mask(s: &str) -> Mask {
+        Mask::from_bits(s.chars().map(|c| c == '#'))
+    }
+
+    #[test]
+    fn single_word() {
+        let a = mask("#.##..##.");
+
+        assert!(a.get(0));
+        assert!(!a.get(1));
+        assert!(!a.get(100));
+        assert_eq!(a.diff(&mask("#.##..##.")), 0);
+        assert_eq!(a.diff(&mask("..#.##.#.")), 5);
+    }
+
+    #[test]
+    fn multiple_words() {
+        let a = "#.".repeat(70);
+        let mut b = a.clone();
+        b.replace_range(1..2, "#");
+        b.replace_range(129..130, "#");
+
+        assert_eq!(mask(&a).diff(&mask(&a)), 0);
+        assert_eq!(mask(&a).diff(&mask(&b)), 2);
+        assert!(mask(&b).get(129));
+        assert_eq!(mask(&a).differences(&mask(&b)), vec![1, 129]);
+    }
+}
@@ -0,0 +1,171 @@
+use crate::{Axis, Pattern, Reflection};
+
+/// A cell that disagrees with its mirror image, as `(x, y)` coordinates.
+///
+/// Flipping either `cell` or `mirror` removes the difference; `cell` is the one above or left
+/// of the mirror.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Smudge {
+    pub cell: (usize, usize),
+    pub mirror: (usize, usize),
+}
+
+/// A reflection together with the smudges that have to be cleaned for it to be perfect.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Repair {
+    pub reflection: Reflection,
+    pub smudges: Vec<Smudge>,
+}
+
+impl Pattern {
+    /// Every cell pair that differs across the mirror of `reflection`.
+    pub fn smudges(&self, reflection: &Reflection) -> Vec<Smudge> {
+        let (lines, len) = match reflection.axis {
+            Axis::Horizontal => (&self.rows, self.height()),
+            Axis::Vertical => (&self.columns, self.width()),
+        };
+        let i = reflection.index;
+        let mut output = vec![];
+
+        for d in 0..i.min(len - i) {
+            let (a, b) = (i - d - 1, i + d);
+
+            for position in lines[a].differences(&lines[b]) {
+                output.push(match reflection.axis {
+                    Axis::Horizontal => Smudge {
+                        cell: (position, a),
+                        mirror: (position, b),
+                    },
+                    Axis::Vertical => Smudge {
+                        cell: (a, position),
+                        mirror: (b, position),
+                    },
+                });
+            }
+        }
+
+        output
+    }
+
+    /// Every way of cleaning exactly `smudges` cells so that some mirror becomes perfect.
+    pub fn repairs(&self, smudges: usize) -> Vec<Repair> {
+        self.find_reflections(smudges)
+            .into_iter()
+            .map(|reflection| Repair {
+                smudges: self.smudges(&reflection),
+                reflection,
+            })
+            .collect()
+    }
+
+    /// The pattern with the smudges of `repair` cleaned and its mirror drawn in.
+    pub fn render_repair(&self, repair: &Repair) -> String {
+        let fixed = repair
+            .smudges
+            .iter()
+            .map(|smudge| smudge.cell)
+            .collect::<Vec<_>>();
+        let index = repair.reflection.index;
+        let mut output = String::new();
+
+        for y in 0..self.height() {
+            if repair.reflection.axis == Axis::Horizontal && y == index {
+                output.push_str(&"─".repeat(self.width()));
+                output.push('\n');
+            }
+
+            for x in 0..self.width() {
+                if repair.reflection.axis == Axis::Vertical && x == index {
+                    output.push('│');
+                }
+
+                let rock = self.get(x, y) != fixed.contains(&(x, y));
+                output.push(if rock { '#' } else { '.' });
+            }
+            output.push('\n');
+        }
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{pattern, FIRST, SECOND};
+
+    #[test]
+    fn smudge_coordinates() {
+        let repairs = pattern(FIRST).repairs(1);
+
+        assert_eq!(repairs.len(), 1);
+        assert_eq!(
+            repairs[0].smudges,
+            vec![Smudge {
+                cell: (0, 0),
+                mirror: (0, 5)
+            }]
+        );
+
+        let repairs = pattern(SECOND).repairs(1);
+
+        assert_eq!(
+            repairs[0].smudges,
+            vec![Smudge {
+                cell: (4, 0),
+                mirror: (4, 1)
+            }]
+        );
+    }
+
+    #[test]
+    fn perfect_mirrors_need_no_cleaning() {
+        let repairs = pattern(FIRST).repairs(0);
+
+        assert_eq!(repairs.len(), 1);
+        assert!(repairs[0].smudges.is_empty());
+    }
+
+    #[test]
+    fn renders_horizontal_mirror() {
+        let pattern = pattern(FIRST);
+        let repair = &pattern.repairs(1)[0];
+
+        assert_eq!(
+            pattern.render_repair(repair),
+            "..##..##.
+..#.##.#.
+##......#
+─────────
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+"
+        );
+    }
+
+    #[test]
+    fn renders_vertical_mirror() {
+        let pattern = pattern(FIRST);
+        let repair = &pattern.repairs(0)[0];
+
+        assert_eq!(
+            pattern.render_repair(repair),
+            "#.##.│.##.
+..#.#│#.#.
+##...│...#
+##...│...#
+..#.#│#.#.
+..##.│.##.
+#.#.#│#.#.
+"
+        );
+    }
+
+    #[test]
+    fn zero_or_many_repairs() {
+        assert!(pattern("#.\n.#").repairs(0).is_empty());
+        assert_eq!(pattern("##\n##").repairs(0).len(), 2);
+    }
+}
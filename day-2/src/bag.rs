@@ -0,0 +1,142 @@
+use crate::game::{parse_cubes, Colour, Game, Round};
+use std::fmt;
+
+/// The cubes in the bag, in the order they were declared.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bag {
+    pub cubes: Vec<(Colour, u32)>,
+}
+
+impl Bag {
+    /// Parses a list such as `12 red, 13 green, 14 blue`, which also fixes the colours games
+    /// may use.
+    pub fn parse(input: &str) -> Result<Bag, String> {
+        Ok(Bag {
+            cubes: parse_cubes(input)?,
+        })
+    }
+
+    pub fn get(&self, colour: &Colour) -> Option<u32> {
+        self.cubes
+            .iter()
+            .find(|(c, _)| c == colour)
+            .map(|(_, count)| *count)
+    }
+
+    pub fn declares(&self, colour: &Colour) -> bool {
+        self.get(colour).is_some()
+    }
+
+    pub fn colours(&self) -> impl Iterator<Item = &Colour> {
+        self.cubes.iter().map(|(colour, _)| colour)
+    }
+
+    /// Whether `round` could have been drawn from this bag.
+    pub fn allows_round(&self, round: &Round) -> bool {
+        round
+            .iter()
+            .all(|(colour, count)| self.get(colour).is_some_and(|max| *count <= max))
+    }
+
+    /// Whether every round of `game` could have been drawn from this bag.
+    pub fn allows(&self, game: &Game) -> bool {
+        game.rounds.iter().all(|round| self.allows_round(round))
+    }
+
+    /// Product of the cube counts, as in part two.
+    pub fn power(&self) -> u64 {
+        self.cubes.iter().map(|(_, count)| *count as u64).product()
+    }
+
+    /// Total number of cubes in the bag.
+    pub fn total(&self) -> u64 {
+        self.cubes.iter().map(|(_, count)| *count as u64).sum()
+    }
+
+    /// The colours that make `game` impossible with this bag; empty when it is possible.
+    pub fn shortfalls(&self, game: &Game) -> Vec<Shortfall> {
+        self.cubes
+            .iter()
+            .filter(|(colour, available)| game.max(colour) > *available)
+            .map(|(colour, available)| Shortfall {
+                colour: colour.clone(),
+                needed: game.max(colour),
+                available: *available,
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for Bag {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let cubes = self
+            .cubes
+            .iter()
+            .map(|(colour, count)| format!("{} {}", count, colour))
+            .collect::<Vec<_>>();
+
+        write!(f, "{}", cubes.join(", "))
+    }
+}
+
+/// A colour of which a game showed more cubes than the bag holds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Shortfall {
+    pub colour: Colour,
+    pub needed: u32,
+    pub available: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn declared_colours() {
+        let bag = Bag::parse("12 red, 13 green, 14 blue, 1 purple").unwrap();
+
+        assert_eq!(bag.get(&"purple".into()), Some(1));
+        assert!(!bag.declares(&"orange".into()));
+        assert_eq!(
+            bag.colours().map(|c| c.0.as_str()).collect::<Vec<_>>(),
+            vec!["red", "green", "blue", "purple"]
+        );
+        assert!(Bag::parse("1 red, 2 red").is_err());
+        assert_eq!(bag.to_string(), "12 red, 13 green, 14 blue, 1 purple");
+    }
+
+    #[test]
+    fn allows_games_within_bag() {
+        let bag = Bag::parse("12 red, 13 green, 14 blue").unwrap();
+
+        assert!(bag.allows(&Game::parse("Game 1: 3 blue, 4 red; 2 green").unwrap()));
+        assert!(!bag.allows(&Game::parse("Game 3: 8 green, 6 blue, 20 red").unwrap()));
+    }
+
+    #[test]
+    fn shortfalls_name_the_colours() {
+        let bag = Bag::parse("12 red, 13 green, 14 blue").unwrap();
+        let game = Game::parse("Game 4: 1 green, 3 red, 6 blue; 3 green, 15 blue, 14 red").unwrap();
+
+        assert_eq!(
+            bag.shortfalls(&game),
+            vec![
+                Shortfall {
+                    colour: "red".into(),
+                    needed: 14,
+                    available: 12
+                },
+                Shortfall {
+                    colour: "blue".into(),
+                    needed: 15,
+                    available: 14
+                }
+            ]
+        );
+        assert!(bag
+            .shortfalls(&Game::parse("Game 1: 1 red").unwrap())
+            .is_empty());
+        assert_eq!(bag.power(), 12 * 13 * 14);
+        assert_eq!(bag.total(), 39);
+    }
+}
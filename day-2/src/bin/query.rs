@@ -0,0 +1,59 @@
+use day_2::{feasible, parse_games, smallest_bag, Bag};
+
+fn process(input: &str, condition: &str, fraction: f64) -> Result<(), String> {
+    let bag = Bag::parse(condition)?;
+    let games = parse_games(input, &bag)?;
+
+    let ids = feasible(&games, &bag)
+        .iter()
+        .map(|game| game.id.to_string())
+        .collect::<Vec<_>>();
+    println!("Feasible with {}: {}", bag, ids.join(", "));
+
+    for game in &games {
+        let minimal = game.minimal_bag(&bag);
+        let shortfalls = bag
+            .shortfalls(game)
+            .iter()
+            .map(|s| format!("{} ({} > {})", s.colour, s.needed, s.available))
+            .collect::<Vec<_>>();
+
+        print!(
+            "Game {}: needs {}, power {}",
+            game.id,
+            minimal,
+            minimal.power()
+        );
+        if !shortfalls.is_empty() {
+            print!(", ruled out by {}", shortfalls.join(", "));
+        }
+        println!();
+    }
+
+    let smallest = smallest_bag(&games, &bag, fraction)?;
+    println!(
+        "Smallest bag for {}% of games: {} ({} cubes)",
+        fraction * 100.0,
+        smallest,
+        smallest.total()
+    );
+
+    Ok(())
+}
+
+fn main() {
+    let args = std::env::args().collect::<Vec<_>>();
+    let bag = args
+        .get(1)
+        .map(String::as_str)
+        .unwrap_or("12 red, 13 green, 14 blue");
+    let fraction = match args.get(2).map(|s| s.parse::<f64>()) {
+        Some(Ok(fraction)) => fraction,
+        Some(Err(err)) => return println!("Error: {}", err),
+        None => 1.0,
+    };
+
+    if let Err(err) = process(include_str!("./input-1.txt"), bag, fraction) {
+        println!("Error: {}", err);
+    }
+}
@@ -0,0 +1,209 @@
+use crate::bag::Bag;
+use std::collections::HashMap;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Colour(pub String);
+
+impl From<&str> for Colour {
+    fn from(s: &str) -> Self {
+        Colour(s.to_owned())
+    }
+}
+
+impl fmt::Display for Colour {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// The cubes shown in one handful, by colour.
+pub type Round = HashMap<Colour, u32>;
+
+/// Parses `count colour` pairs separated by commas, in the order they are written.
+///
+/// A colour may only appear once.
+pub fn parse_cubes(input: &str) -> Result<Vec<(Colour, u32)>, String> {
+    let mut cubes: Vec<(Colour, u32)> = vec![];
+
+    for cube in input.split(',') {
+        let cube = cube.trim();
+
+        let (count, colour) = cube
+            .split_once(' ')
+            .ok_or(format!("Expected '<count> <colour>', got '{}'", cube))?;
+        let count = count
+            .parse::<u32>()
+            .map_err(|_| format!("Invalid count: {}", count))?;
+        let colour = colour.trim();
+
+        if colour.is_empty() || !colour.chars().all(|c| c.is_alphabetic()) {
+            return Err(format!("Invalid colour: {}", colour));
+        }
+
+        if cubes.iter().any(|(c, _)| c.0 == colour) {
+            return Err(format!("Duplicated colour: {}", colour));
+        }
+
+        cubes.push((colour.into(), count));
+    }
+
+    Ok(cubes)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    pub id: u32,
+    pub rounds: Vec<Round>,
+}
+
+impl Game {
+    /// Parses `Game <id>: <round>; <round>; ...`, where each round is a list of cubes.
+    pub fn parse(line: &str) -> Result<Game, String> {
+        let (header, rounds) = line
+            .split_once(':')
+            .ok_or("Expected 'Game <id>:'".to_owned())?;
+
+        let id = header
+            .trim()
+            .strip_prefix("Game ")
+            .ok_or("Expected 'Game <id>:'".to_owned())?;
+        let id = id
+            .trim()
+            .parse::<u32>()
+            .map_err(|_| format!("Invalid game id: {}", id.trim()))?;
+
+        let rounds = rounds
+            .split(';')
+            .enumerate()
+            .map(|(i, round)| {
+                parse_cubes(round)
+                    .map(|cubes| cubes.into_iter().collect())
+                    .map_err(|err| format!("Round {}: {}", i + 1, err))
+            })
+            .collect::<Result<Vec<Round>, String>>()?;
+
+        Ok(Game { id, rounds })
+    }
+
+    /// The most cubes of `colour` shown in any single round.
+    pub fn max(&self, colour: &Colour) -> u32 {
+        self.rounds
+            .iter()
+            .filter_map(|round| round.get(colour))
+            .max()
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// The fewest cubes of each colour of `bag` that make this game possible.
+    pub fn minimal_bag(&self, bag: &Bag) -> Bag {
+        Bag {
+            cubes: bag
+                .colours()
+                .map(|colour| (colour.clone(), self.max(colour)))
+                .collect(),
+        }
+    }
+
+    /// Every colour shown in any round.
+    pub fn colours(&self) -> Vec<&Colour> {
+        let mut colours = self
+            .rounds
+            .iter()
+            .flat_map(|round| round.keys())
+            .collect::<Vec<_>>();
+
+        colours.sort();
+        colours.dedup();
+        colours
+    }
+}
+
+fn parse_game(line: &str, bag: &Bag) -> Result<Game, String> {
+    let game = Game::parse(line)?;
+
+    if let Some(colour) = game.colours().iter().find(|c| !bag.declares(c)) {
+        return Err(format!("Unknown colour: {}", colour));
+    }
+
+    Ok(game)
+}
+
+/// Parses one game per line, rejecting colours the bag does not declare.
+pub fn parse_games(input: &str, bag: &Bag) -> Result<Vec<Game>, String> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| parse_game(line, bag).map_err(|err| format!("Line {}: {}", i + 1, err)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round(cubes: &[(&str, u32)]) -> Round {
+        cubes.iter().map(|(c, n)| (Colour::from(*c), *n)).collect()
+    }
+
+    #[test]
+    fn parses_game() {
+        let game = Game::parse("Game 12: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green").unwrap();
+
+        assert_eq!(game.id, 12);
+        assert_eq!(
+            game.rounds,
+            vec![
+                round(&[("blue", 3), ("red", 4)]),
+                round(&[("red", 1), ("green", 2), ("blue", 6)]),
+                round(&[("green", 2)]),
+            ]
+        );
+        assert_eq!(game.max(&"blue".into()), 6);
+        assert_eq!(game.max(&"purple".into()), 0);
+    }
+
+    #[test]
+    fn rejects_malformed_games() {
+        assert_eq!(
+            Game::parse("Game x: 1 red").unwrap_err(),
+            "Invalid game id: x"
+        );
+        assert_eq!(
+            Game::parse("Round 1: 1 red").unwrap_err(),
+            "Expected 'Game <id>:'"
+        );
+        assert_eq!(
+            Game::parse("Game 1: 1 red; 2 blue, 3 blue").unwrap_err(),
+            "Round 2: Duplicated colour: blue"
+        );
+        assert_eq!(
+            Game::parse("Game 1: 1 red;").unwrap_err(),
+            "Round 2: Expected '<count> <colour>', got ''"
+        );
+        assert_eq!(
+            Game::parse("Game 1: red 1").unwrap_err(),
+            "Round 1: Invalid count: red"
+        );
+    }
+
+    #[test]
+    fn games_only_use_colours_of_the_bag() {
+        let bag = Bag::parse("12 red, 13 green, 14 blue").unwrap();
+
+        assert_eq!(
+            parse_games("Game 1: 1 red\nGame 2: 2 purple, 1 red", &bag).unwrap_err(),
+            "Line 2: Unknown colour: purple"
+        );
+
+        let bag = Bag::parse("2 purple, 1 red").unwrap();
+        assert_eq!(
+            parse_games("Game 1: 1 red\nGame 2: 2 purple, 1 red", &bag)
+                .unwrap()
+                .len(),
+            2
+        );
+    }
+}
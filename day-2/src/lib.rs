@@ -0,0 +1,7 @@
+pub mod bag;
+pub mod game;
+pub mod queries;
+
+pub use bag::{Bag, Shortfall};
+pub use game::{parse_games, Colour, Game, Round};
+pub use queries::{feasible, smallest_bag};
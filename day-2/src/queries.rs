@@ -0,0 +1,214 @@
+use crate::bag::Bag;
+use crate::game::Game;
+
+/// The games that could have been played with `bag`.
+pub fn feasible<'a>(games: &'a [Game], bag: &Bag) -> Vec<&'a Game> {
+    games.iter().filter(|game| bag.allows(game)).collect()
+}
+
+/// The bag with the fewest cubes, over the colours of `bag`, that makes at least `fraction`
+/// of `games` possible. Ties go to the bag with fewer cubes of the earlier declared colours.
+///
+/// Tries every combination of per-game maxima for all colours but the last, so the cost grows
+/// with the number of colours.
+pub fn smallest_bag(games: &[Game], bag: &Bag, fraction: f64) -> Result<Bag, String> {
+    if !(0.0..=1.0).contains(&fraction) {
+        return Err(format!("Invalid fraction: {}", fraction));
+    }
+
+    let needs = games
+        .iter()
+        .map(|game| {
+            game.minimal_bag(bag)
+                .cubes
+                .into_iter()
+                .map(|(_, count)| count)
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let wanted = required(fraction, games.len());
+
+    let colours = bag.colours().cloned().collect::<Vec<_>>();
+
+    if colours.is_empty() {
+        return Ok(Bag { cubes: vec![] });
+    }
+
+    let mut best: Option<Vec<u32>> = None;
+    let mut counts = vec![];
+    search(&needs, wanted, &mut counts, colours.len(), &mut best);
+
+    let counts = best.ok_or("No bag makes enough games possible".to_owned())?;
+
+    Ok(Bag {
+        cubes: colours.into_iter().zip(counts).collect(),
+    })
+}
+
+/// The fewest of `len` games whose share `k / len` is at least `fraction`, so that 0.07 of
+/// 100 games is 7 even though `0.07 * 100` is a little over 7. Starts from the rounded product.
+fn required(fraction: f64, len: usize) -> usize {
+    let meets = |k: usize| k as f64 / len as f64 >= fraction;
+    let mut k = ((fraction * len as f64).round() as usize).min(len);
+
+    while k > 0 && meets(k - 1) {
+        k -= 1;
+    }
+    while k < len && !meets(k) {
+        k += 1;
+    }
+
+    k
+}
+
+/// Fixes the count of colour `counts.len()` to each useful value in turn, and on the last
+/// colour takes just enough cubes for `wanted` of the games that fit the fixed counts.
+fn search(
+    needs: &[Vec<u32>],
+    wanted: usize,
+    counts: &mut Vec<u32>,
+    colours: usize,
+    best: &mut Option<Vec<u32>>,
+) {
+    let fits = needs
+        .iter()
+        .filter(|need| need.iter().zip(counts.iter()).all(|(n, c)| n <= c))
+        .collect::<Vec<_>>();
+
+    if fits.len() < wanted {
+        return;
+    }
+
+    let colour = counts.len();
+    let mut values = fits.iter().map(|need| need[colour]).collect::<Vec<_>>();
+    values.sort_unstable();
+
+    if colour == colours - 1 {
+        counts.push(match wanted {
+            0 => 0,
+            _ => values[wanted - 1],
+        });
+
+        let total = |c: &[u32]| c.iter().map(|n| *n as u64).sum::<u64>();
+        let better = match best {
+            Some(b) => (total(counts), counts.as_slice()) < (total(b), b.as_slice()),
+            None => true,
+        };
+        if better {
+            *best = Some(counts.clone());
+        }
+
+        counts.pop();
+        return;
+    }
+
+    values.insert(0, 0);
+    values.dedup();
+
+    for value in values {
+        counts.push(value);
+        search(needs, wanted, counts, colours, best);
+        counts.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::parse_games;
+
+    const GAMES: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+    fn setup() -> (Vec<Game>, Bag) {
+        let bag = Bag::parse("12 red, 13 green, 14 blue").unwrap();
+
+        (parse_games(GAMES, &bag).unwrap(), bag)
+    }
+
+    #[test]
+    fn feasible_games() {
+        let (games, bag) = setup();
+
+        assert_eq!(
+            feasible(&games, &bag)
+                .iter()
+                .map(|game| game.id)
+                .collect::<Vec<_>>(),
+            vec![1, 2, 5]
+        );
+    }
+
+    #[test]
+    fn minimal_bags_and_power() {
+        let (games, bag) = setup();
+
+        assert_eq!(
+            games[0].minimal_bag(&bag),
+            Bag::parse("4 red, 2 green, 6 blue").unwrap()
+        );
+        assert_eq!(
+            games
+                .iter()
+                .map(|game| game.minimal_bag(&bag).power())
+                .sum::<u64>(),
+            2286
+        );
+    }
+
+    #[test]
+    fn smallest_bag_for_fraction() {
+        let (games, bag) = setup();
+
+        assert_eq!(
+            smallest_bag(&games, &bag, 1.0).unwrap(),
+            Bag::parse("20 red, 13 green, 15 blue").unwrap()
+        );
+        // Dropping game 3 saves more cubes than dropping game 4
+        assert_eq!(
+            smallest_bag(&games, &bag, 0.8).unwrap(),
+            Bag::parse("14 red, 3 green, 15 blue").unwrap()
+        );
+        assert_eq!(
+            smallest_bag(&games, &bag, 0.0).unwrap(),
+            Bag::parse("0 red, 0 green, 0 blue").unwrap()
+        );
+        assert!(smallest_bag(&games, &bag, 1.5).is_err());
+    }
+
+    #[test]
+    fn required_games_avoid_float_rounding() {
+        assert_eq!(required(0.07, 100), 7);
+        assert_eq!(required(0.56, 25), 14);
+        assert_eq!(required(0.5, 5), 3);
+        assert_eq!(required(0.0, 5), 0);
+        assert_eq!(required(1.0, 100), 100);
+        assert_eq!(required(0.1, 3), 1);
+        assert_eq!(required(0.29, 100), 29);
+        assert_eq!(required(0.5, 0), 0);
+
+        for len in 1..200 {
+            for k in 0..=len {
+                let fraction = k as f64 / len as f64;
+
+                assert_eq!(required(fraction, len), k, "{} of {}", k, len);
+                assert_eq!(required(fraction + 1e-12, len), (k + 1).min(len));
+            }
+        }
+    }
+
+    #[test]
+    fn smallest_bag_covers_requested_games() {
+        let (games, bag) = setup();
+
+        for fraction in [0.2, 0.4, 0.6, 0.8, 1.0] {
+            let smallest = smallest_bag(&games, &bag, fraction).unwrap();
+            let wanted = required(fraction, games.len());
+
+            assert!(feasible(&games, &smallest).len() >= wanted);
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use day_3::{Aggregate, PartGraph, Schematic};
+
+/// Sums `aggregate` over every `symbol` with exactly `count` numbers around it.
+fn process(input: &str, symbol: char, count: usize, aggregate: Aggregate) -> Result<u64, String> {
+    let schematic = Schematic::parse(input)?;
+    let graph = PartGraph::new(&schematic);
+
+    let mut total = 0u64;
+
+    for (gear, numbers) in graph.gears(symbol, count) {
+        let overflow = || {
+            format!(
+                "Result overflows at line {}, column {}",
+                gear.row + 1,
+                gear.column + 1
+            )
+        };
+
+        let value = aggregate
+            .apply(numbers.iter().map(|n| n.value as u64))
+            .ok_or_else(overflow)?;
+        total = total.checked_add(value).ok_or_else(overflow)?;
+    }
+
+    Ok(total)
+}
+
+fn main() {
+    let input_file = include_str!("./input-1.txt");
+    let args = std::env::args().collect::<Vec<_>>();
+
+    // Usage: gears [symbol] [count] [sum|product|count|min|max]
+    let symbol = args.get(1).and_then(|s| s.chars().next()).unwrap_or('*');
+    let count = match args.get(2).map(|s| s.parse::<usize>()) {
+        Some(Ok(count)) => count,
+        Some(Err(err)) => return println!("Error: {}", err),
+        None => 2,
+    };
+    let aggregate = args
+        .get(3)
+        .map(|name| Aggregate::from_name(name))
+        .unwrap_or(Ok(Aggregate::Product));
+
+    match aggregate.and_then(|aggregate| process(input_file, symbol, count, aggregate)) {
+        Ok(result) => println!("{}", result),
+        Err(err) => println!("Error: {}", err),
+    }
+}
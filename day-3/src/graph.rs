@@ -0,0 +1,211 @@
+use crate::schematic::{Number, Schematic, Symbol};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Aggregate {
+    Sum,
+    Product,
+    Count,
+    Min,
+    Max,
+}
+
+impl Aggregate {
+    pub fn from_name(name: &str) -> Result<Aggregate, String> {
+        match name {
+            "sum" => Ok(Aggregate::Sum),
+            "product" => Ok(Aggregate::Product),
+            "count" => Ok(Aggregate::Count),
+            "min" => Ok(Aggregate::Min),
+            "max" => Ok(Aggregate::Max),
+            _ => Err(format!("Unknown aggregate: {}", name)),
+        }
+    }
+
+    /// Combines `values`, or `None` if the result overflows; min and max of nothing are 0.
+    pub fn apply(&self, mut values: impl Iterator<Item = u64>) -> Option<u64> {
+        match self {
+            Aggregate::Sum => values.try_fold(0u64, |acc, v| acc.checked_add(v)),
+            Aggregate::Product => values.try_fold(1u64, |acc, v| acc.checked_mul(v)),
+            Aggregate::Count => Some(values.count() as u64),
+            Aggregate::Min => Some(values.min().unwrap_or(0)),
+            Aggregate::Max => Some(values.max().unwrap_or(0)),
+        }
+    }
+}
+
+/// Which numbers touch which symbols, worked out once for the whole schematic.
+///
+/// Numbers and symbols are referred to by their index in the schematic.
+#[derive(Debug, Clone)]
+pub struct PartGraph<'a> {
+    schematic: &'a Schematic,
+    symbols_of: Vec<Vec<usize>>,
+    numbers_of: Vec<Vec<usize>>,
+}
+
+impl<'a> PartGraph<'a> {
+    pub fn new(schematic: &'a Schematic) -> PartGraph<'a> {
+        let at = schematic
+            .symbols
+            .iter()
+            .enumerate()
+            .map(|(i, s)| ((s.row, s.column), i))
+            .collect::<HashMap<_, _>>();
+
+        let mut symbols_of = vec![vec![]; schematic.numbers.len()];
+        let mut numbers_of = vec![vec![]; schematic.symbols.len()];
+
+        for (n, number) in schematic.numbers.iter().enumerate() {
+            let span = number.span;
+
+            // The ring of cells around the span, clipped at the top and left edges
+            for row in span.row.saturating_sub(1)..=span.row + 1 {
+                for column in span.start.saturating_sub(1)..=span.end {
+                    if let Some(s) = at.get(&(row, column)) {
+                        symbols_of[n].push(*s);
+                        numbers_of[*s].push(n);
+                    }
+                }
+            }
+        }
+
+        PartGraph {
+            schematic,
+            symbols_of,
+            numbers_of,
+        }
+    }
+
+    pub fn symbols_of(&self, number: usize) -> Vec<&'a Symbol> {
+        self.symbols_of[number]
+            .iter()
+            .map(|s| &self.schematic.symbols[*s])
+            .collect()
+    }
+
+    /// Numbers next to symbol `symbol`, in reading order.
+    pub fn numbers_of(&self, symbol: usize) -> Vec<&'a Number> {
+        self.numbers_of[symbol]
+            .iter()
+            .map(|n| &self.schematic.numbers[*n])
+            .collect()
+    }
+
+    /// Numbers next to at least one symbol.
+    pub fn part_numbers(&self) -> Vec<&'a Number> {
+        self.schematic
+            .numbers
+            .iter()
+            .zip(&self.symbols_of)
+            .filter(|(_, symbols)| !symbols.is_empty())
+            .map(|(number, _)| number)
+            .collect()
+    }
+
+    /// Every `c` symbol with exactly `count` numbers around it, along with those numbers.
+    pub fn gears(&self, c: char, count: usize) -> Vec<(&'a Symbol, Vec<&'a Number>)> {
+        self.schematic
+            .symbols
+            .iter()
+            .enumerate()
+            .filter(|(s, symbol)| symbol.c == c && self.numbers_of[*s].len() == count)
+            .map(|(s, symbol)| (symbol, self.numbers_of(s)))
+            .collect()
+    }
+
+    /// `aggregate` of the numbers around each `c` symbol, or `None` where it overflows.
+    pub fn aggregates(&self, c: char, aggregate: Aggregate) -> Vec<(&'a Symbol, Option<u64>)> {
+        self.schematic
+            .symbols
+            .iter()
+            .enumerate()
+            .filter(|(_, symbol)| symbol.c == c)
+            .map(|(s, symbol)| {
+                let values = self.numbers_of(s).into_iter().map(|n| n.value as u64);
+
+                (symbol, aggregate.apply(values))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schematic::tests::{values, INPUT};
+
+    #[test]
+    fn matches_schematic_scan() {
+        for input in [INPUT, include_str!("./bin/input-1.txt")] {
+            let schematic = Schematic::parse(input).unwrap();
+            let graph = PartGraph::new(&schematic);
+
+            assert_eq!(graph.part_numbers(), schematic.part_numbers());
+
+            for (s, symbol) in schematic.symbols.iter().enumerate() {
+                assert_eq!(graph.numbers_of(s), schematic.adjacent_numbers(symbol));
+            }
+        }
+    }
+
+    #[test]
+    fn puzzle_gears() {
+        let schematic = Schematic::parse(INPUT).unwrap();
+        let graph = PartGraph::new(&schematic);
+        let gears = graph.gears('*', 2);
+
+        assert_eq!(gears.len(), 2);
+        assert_eq!(values(&gears[0].1), vec![467, 35]);
+        assert_eq!(
+            gears
+                .iter()
+                .map(|(_, numbers)| Aggregate::Product.apply(numbers.iter().map(|n| n.value as u64)))
+                .sum::<Option<u64>>(),
+            Some(467835)
+        );
+    }
+
+    #[test]
+    fn gears_by_any_symbol_and_count() {
+        let schematic = Schematic::parse("1.2\n.#.\n3..\n..$\n..4").unwrap();
+        let graph = PartGraph::new(&schematic);
+
+        assert_eq!(graph.gears('#', 3).len(), 1);
+        assert!(graph.gears('#', 2).is_empty());
+        assert_eq!(values(&graph.gears('$', 1)[0].1), vec![4]);
+        assert_eq!(
+            graph.symbols_of(0),
+            vec![&Symbol {
+                c: '#',
+                row: 1,
+                column: 1
+            }]
+        );
+    }
+
+    #[test]
+    fn per_symbol_aggregates() {
+        let schematic = Schematic::parse("1.2\n.#.\n3..\n..$\n..4").unwrap();
+        let graph = PartGraph::new(&schematic);
+        let aggregate = |a| graph.aggregates('#', a)[0].1;
+
+        assert_eq!(aggregate(Aggregate::Sum), Some(6));
+        assert_eq!(aggregate(Aggregate::Product), Some(6));
+        assert_eq!(aggregate(Aggregate::Count), Some(3));
+        assert_eq!(aggregate(Aggregate::Min), Some(1));
+        assert_eq!(aggregate(Aggregate::Max), Some(3));
+        assert!(graph.aggregates('?', Aggregate::Sum).is_empty());
+    }
+
+    #[test]
+    fn overflowing_aggregates() {
+        let schematic = Schematic::parse("1111.2222\n3333#4444\n5555.6666").unwrap();
+        let graph = PartGraph::new(&schematic);
+        let aggregate = |a| graph.aggregates('#', a)[0].1;
+
+        assert_eq!(aggregate(Aggregate::Product), None);
+        assert_eq!(aggregate(Aggregate::Sum), Some(23331));
+        assert_eq!(Aggregate::Sum.apply([u64::MAX, 1].into_iter()), None);
+    }
+}
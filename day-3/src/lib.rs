@@ -0,0 +1,5 @@
+pub mod graph;
+pub mod schematic;
+
+pub use graph::{Aggregate, PartGraph};
+pub use schematic::{Number, Schematic, Span, Symbol};
@@ -0,0 +1,201 @@
+/// Where a number sits, as columns `start..end` of `row`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    pub row: usize,
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    /// Whether the cell at `row`, `column` touches the span, diagonals included.
+    pub fn touches(&self, row: usize, column: usize) -> bool {
+        row + 1 >= self.row && row <= self.row + 1 && column + 1 >= self.start && column <= self.end
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Number {
+    pub value: u32,
+    pub span: Span,
+}
+
+/// Any character other than a digit or `.`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Symbol {
+    pub c: char,
+    pub row: usize,
+    pub column: usize,
+}
+
+/// An engine schematic read line by line, so rows may have any length.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Schematic {
+    pub numbers: Vec<Number>,
+    pub symbols: Vec<Symbol>,
+}
+
+impl Schematic {
+    pub fn parse(input: &str) -> Result<Schematic, String> {
+        let mut numbers = vec![];
+        let mut symbols = vec![];
+
+        for (row, line) in input.lines().enumerate() {
+            let cells = line.trim_end().chars().collect::<Vec<_>>();
+            let mut start = None;
+
+            // A trailing '.' closes any number still open at the end of the row
+            for (column, c) in cells.iter().copied().chain(['.']).enumerate() {
+                if c.is_ascii_digit() {
+                    start.get_or_insert(column);
+                    continue;
+                }
+
+                if let Some(start) = start.take() {
+                    let digits = cells[start..column].iter().collect::<String>();
+                    let value = digits.parse::<u32>().map_err(|_| {
+                        format!(
+                            "Number too large: {} at line {}, column {}",
+                            digits,
+                            row + 1,
+                            start + 1
+                        )
+                    })?;
+
+                    numbers.push(Number {
+                        value,
+                        span: Span {
+                            row,
+                            start,
+                            end: column,
+                        },
+                    });
+                }
+
+                if column < cells.len() && c != '.' {
+                    symbols.push(Symbol { c, row, column });
+                }
+            }
+        }
+
+        Ok(Schematic { numbers, symbols })
+    }
+
+    pub fn adjacent_symbols(&self, number: &Number) -> Vec<&Symbol> {
+        self.symbols
+            .iter()
+            .filter(|s| number.span.touches(s.row, s.column))
+            .collect()
+    }
+
+    pub fn adjacent_numbers(&self, symbol: &Symbol) -> Vec<&Number> {
+        self.numbers
+            .iter()
+            .filter(|n| n.span.touches(symbol.row, symbol.column))
+            .collect()
+    }
+
+    /// Numbers next to at least one symbol.
+    pub fn part_numbers(&self) -> Vec<&Number> {
+        self.numbers
+            .iter()
+            .filter(|n| !self.adjacent_symbols(n).is_empty())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    pub(crate) const INPUT: &str = "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+
+    pub(crate) fn values(numbers: &[&Number]) -> Vec<u32> {
+        numbers.iter().map(|n| n.value).collect()
+    }
+
+    #[test]
+    fn number_spans() {
+        let schematic = Schematic::parse(INPUT).unwrap();
+
+        assert_eq!(schematic.numbers.len(), 10);
+        assert_eq!(
+            schematic.numbers[1],
+            Number {
+                value: 114,
+                span: Span {
+                    row: 0,
+                    start: 5,
+                    end: 8
+                }
+            }
+        );
+        assert_eq!(
+            schematic.symbols[0],
+            Symbol {
+                c: '*',
+                row: 1,
+                column: 3
+            }
+        );
+        assert_eq!(
+            schematic
+                .part_numbers()
+                .iter()
+                .map(|n| n.value)
+                .sum::<u32>(),
+            4361
+        );
+    }
+
+    #[test]
+    fn numbers_do_not_wrap_to_the_next_row() {
+        let schematic = Schematic::parse("...12\n*....").unwrap();
+
+        assert!(schematic.part_numbers().is_empty());
+
+        let schematic = Schematic::parse("....*\n12...").unwrap();
+
+        assert!(schematic.part_numbers().is_empty());
+    }
+
+    #[test]
+    fn numbers_at_row_ends() {
+        let schematic = Schematic::parse("..#12\n.....\n34...").unwrap();
+
+        assert_eq!(values(&schematic.part_numbers()), vec![12]);
+        assert_eq!(
+            schematic.numbers[0].span,
+            Span {
+                row: 0,
+                start: 3,
+                end: 5
+            }
+        );
+    }
+
+    #[test]
+    fn wide_and_tall_schematics() {
+        // More columns than rows, where indexing by row count used to land on the wrong cell
+        let wide = Schematic::parse("1.........2\n.........*.").unwrap();
+        assert_eq!(values(&wide.part_numbers()), vec![2]);
+
+        let tall = Schematic::parse("1.\n..\n..\n.*\n.5").unwrap();
+        assert_eq!(values(&tall.part_numbers()), vec![5]);
+    }
+
+    #[test]
+    fn rows_of_different_length() {
+        let schematic = Schematic::parse("12\n..*\n.\n7%").unwrap();
+
+        assert_eq!(values(&schematic.part_numbers()), vec![12, 7]);
+    }
+}
@@ -1,4 +1,4 @@
-use indicatif::ProgressIterator;
+use indicatif::{ProgressBar, ProgressIterator};
 
 #[derive(Clone, Debug)]
 struct Map {
@@ -14,6 +14,15 @@ fn parse_numbers(input: &str) -> Vec<u64> {
         .collect()
 }
 
+/// A progress bar that only shows with `-v`.
+fn progress_bar(len: usize) -> ProgressBar {
+    if trace::enabled(trace::Level::Info) {
+        ProgressBar::new(len as u64)
+    } else {
+        ProgressBar::hidden()
+    }
+}
+
 // Find the lowest location number from the seed.
 fn process(input: &str) -> Result<u64, ()> {
     let lines: Vec<&str> = input
@@ -25,10 +34,8 @@ fn process(input: &str) -> Result<u64, ()> {
 
     let mut maps: Vec<Vec<Map>> = vec![];
 
-    for i in 1..lines.len() {
-        let line = lines[i];
-
-        if line.len() == 0 {
+    for line in lines.iter().skip(1) {
+        if line.is_empty() {
             maps.push(vec![]);
         } else {
             let numbers = parse_numbers(line);
@@ -47,9 +54,11 @@ fn process(input: &str) -> Result<u64, ()> {
         }
     }
 
+    let bar = progress_bar(seed_numbers.len());
+
     seed_numbers
         .into_iter()
-        .progress()
+        .progress_with(bar)
         .map(|sn| {
             maps.clone().into_iter().fold(sn, |mut acc, map| {
                 for m in &map {
@@ -67,6 +76,8 @@ fn process(input: &str) -> Result<u64, ()> {
 }
 
 fn main() {
+    trace::init();
+
     let input = include_str!("./input-1.txt");
     if let Ok(output) = process(input) {
         println!("{}", output);
@@ -1,6 +1,6 @@
 use std::ops::Range;
 
-use indicatif::ProgressIterator;
+use indicatif::{ProgressBar, ProgressIterator};
 
 #[derive(Clone, Debug)]
 struct Map {
@@ -16,8 +16,8 @@ fn parse_numbers(input: &str) -> Vec<i64> {
         .collect()
 }
 
-fn translate(maps: &Vec<Vec<Map>>, from: i64) -> i64 {
-    maps.into_iter().fold(from, |mut acc, map| {
+fn translate(maps: &[Vec<Map>], from: i64) -> i64 {
+    maps.iter().fold(from, |mut acc, map| {
         for m in map {
             // This is currently hardcoded
             if acc >= m.destination && acc < m.destination + m.range {
@@ -30,6 +30,15 @@ fn translate(maps: &Vec<Vec<Map>>, from: i64) -> i64 {
     })
 }
 
+/// A progress bar that only shows with `-v`.
+fn progress_bar(len: usize) -> ProgressBar {
+    if trace::enabled(trace::Level::Info) {
+        ProgressBar::new(len as u64)
+    } else {
+        ProgressBar::hidden()
+    }
+}
+
 // Find the lowest location number from the seed.
 fn process(input: &str) -> Result<i64, ()> {
     let lines: Vec<&str> = input
@@ -39,7 +48,7 @@ fn process(input: &str) -> Result<i64, ()> {
 
     let seed_number_pairs = parse_numbers(lines.first().unwrap());
 
-    println!("Generating seed numbers...");
+    trace::info!("Generating seed numbers...");
 
     let seed_numbers = seed_number_pairs
         .chunks(2)
@@ -51,12 +60,12 @@ fn process(input: &str) -> Result<i64, ()> {
 
     let mut maps: Vec<Vec<Map>> = vec![];
 
-    println!("Creating maps...");
+    trace::info!("Creating maps...");
 
-    for i in (1..lines.len()).progress() {
-        let line = lines[i];
+    let bar = progress_bar(lines.len() - 1);
 
-        if line.len() == 0 {
+    for line in lines.iter().skip(1).progress_with(bar) {
+        if line.is_empty() {
             maps.push(vec![]);
         } else {
             let numbers = parse_numbers(line);
@@ -82,7 +91,7 @@ fn process(input: &str) -> Result<i64, ()> {
     // Trying to find the lowest location matchin one of the seed ranges
     maps.reverse();
 
-    println!("Finding location...");
+    trace::info!("Finding location...");
 
     'outer: loop {
         let result = translate(&maps, location);
@@ -99,6 +108,8 @@ fn process(input: &str) -> Result<i64, ()> {
 }
 
 fn main() {
+    trace::init();
+
     let input = include_str!("./input-1.txt");
     if let Ok(output) = process(input) {
         println!("{}", output);
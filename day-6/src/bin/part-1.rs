@@ -1,5 +1,5 @@
 fn parse_numbers(input: &str) -> Vec<u64> {
-    println!("{:?}", input);
+    trace::debug!("{:?}", input);
     input
         .split_ascii_whitespace()
         .filter_map(|s| s.parse::<u64>().ok())
@@ -9,8 +9,7 @@ fn parse_numbers(input: &str) -> Vec<u64> {
 fn process(input: &str) -> Result<u64, &str> {
     let result = input
         .lines()
-        .into_iter()
-        .map(|x| parse_numbers(x))
+        .map(parse_numbers)
         .collect::<Vec<_>>()
         .chunks(2)
         .map(|v| {
@@ -34,7 +33,7 @@ fn process(input: &str) -> Result<u64, &str> {
 
                     count
                 })
-                .fold(1, |acc, s| acc * s)
+                .product::<u64>()
         })
         .sum();
 
@@ -42,6 +41,8 @@ fn process(input: &str) -> Result<u64, &str> {
 }
 
 fn main() {
+    trace::init();
+
     let input = include_str!("./input.txt");
     if let Ok(result) = process(input) {
         println!("{:?}", result);
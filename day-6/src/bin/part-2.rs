@@ -1,7 +1,6 @@
 fn process(input: &str) -> Result<u64, &str> {
     let result = input
         .lines()
-        .into_iter()
         .map(|x| {
             x.split_ascii_whitespace()
                 .filter(|s| s.chars().all(char::is_numeric))
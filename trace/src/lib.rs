@@ -0,0 +1,112 @@
+//! Diagnostic output for the puzzle binaries, silent unless asked for with `-v` or `-vv`.
+//!
+//! Messages go to stderr so the answer on stdout stays clean.
+
+use std::sync::atomic::{AtomicU8, Ordering};
+
+static VERBOSITY: AtomicU8 = AtomicU8::new(0);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    /// Progress and notable values, shown with `-v`.
+    Info = 1,
+    /// Step-by-step detail, shown with `-vv`.
+    Debug = 2,
+}
+
+pub fn set_verbosity(verbosity: u8) {
+    VERBOSITY.store(verbosity, Ordering::Relaxed);
+}
+
+pub fn verbosity() -> u8 {
+    VERBOSITY.load(Ordering::Relaxed)
+}
+
+pub fn enabled(level: Level) -> bool {
+    verbosity() >= level as u8
+}
+
+/// Counts `-v`, `-vv`, ... flags and returns the verbosity along with the other arguments.
+pub fn split_args(args: impl IntoIterator<Item = String>) -> (u8, Vec<String>) {
+    let mut verbosity = 0u8;
+    let mut rest = vec![];
+
+    for arg in args {
+        match arg.strip_prefix('-') {
+            Some(flags) if !flags.is_empty() && flags.chars().all(|c| c == 'v') => {
+                verbosity = verbosity.saturating_add(flags.len() as u8);
+            }
+            _ => rest.push(arg),
+        }
+    }
+
+    (verbosity, rest)
+}
+
+/// Sets the verbosity from the command line and returns the remaining arguments, starting with
+/// the program name as `std::env::args` does.
+pub fn init() -> Vec<String> {
+    let (verbosity, args) = split_args(std::env::args());
+    set_verbosity(verbosity);
+
+    args
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)*) => {
+        if $crate::enabled($crate::Level::Info) {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        if $crate::enabled($crate::Level::Debug) {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn counts_verbose_flags() {
+        assert_eq!(split_args(args(&["part-1"])), (0, args(&["part-1"])));
+        assert_eq!(
+            split_args(args(&["part-1", "-v", "plain"])),
+            (1, args(&["part-1", "plain"]))
+        );
+        assert_eq!(
+            split_args(args(&["part-1", "-vv", "svg", "-v"])),
+            (3, args(&["part-1", "svg"]))
+        );
+    }
+
+    #[test]
+    fn keeps_other_flags() {
+        assert_eq!(
+            split_args(args(&["-", "-x", "--verbose"])),
+            (0, args(&["-", "-x", "--verbose"]))
+        );
+    }
+
+    #[test]
+    fn levels() {
+        set_verbosity(1);
+        assert!(enabled(Level::Info));
+        assert!(!enabled(Level::Debug));
+
+        set_verbosity(2);
+        assert!(enabled(Level::Debug));
+        set_verbosity(0);
+    }
+}